@@ -0,0 +1,57 @@
+// Compare the flat bucket arrays against the map-based buckets, run with "cargo bench --bench buckets"
+use ark_mnt4_298::G1Projective;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+use std::time::{Duration, Instant};
+use msm::pippenger::pippenger;
+use msm::sid_pippenger::sid_pippenger;
+use msm::subsum_pippenger::subsum_pippenger;
+use msm::flat_pippenger::flat_pippenger;
+use msm::flat_sid_pippenger::flat_sid_pippenger;
+
+// Number of timed runs per measurement, the median is reported
+const NUM_RUNS: usize = 5;
+
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Median runtime of f over NUM_RUNS runs
+fn time(f: impl Fn() -> G1Projective) -> Duration {
+    let mut durations: Vec<Duration> = (0..NUM_RUNS).map(|_| {
+        let start = Instant::now();
+        let _ = std::hint::black_box(f());
+        start.elapsed()
+    }).collect();
+    durations.sort();
+    durations[NUM_RUNS / 2]
+}
+
+fn main() {
+    println!("{:>6} {:>6} {:>16} {:>16} {:>16} {:>16} {:>16}", "n", "window", "pippenger", "flat_pippenger", "subsum_pippenger", "sid_pippenger", "flat_sid_pippenger");
+
+    for log_n in [8, 10, 12] {
+        let num_points = 1 << log_n;
+        let points = generate_points(num_points);
+        let scalars = generate_scalars(num_points);
+
+        for window_size in [4, 8, 12] {
+            println!(
+                "{:>6} {:>6} {:>16?} {:>16?} {:>16?} {:>16?} {:>16?}",
+                num_points,
+                window_size,
+                time(|| pippenger(&points, &scalars, window_size)),
+                time(|| flat_pippenger(&points, &scalars, window_size)),
+                time(|| subsum_pippenger(&points, &scalars, window_size)),
+                time(|| sid_pippenger(&points, &scalars, window_size)),
+                time(|| flat_sid_pippenger(&points, &scalars, window_size)),
+            );
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// Criterion sweep over every algorithm, input size, window and curve, run with "cargo bench --bench msm"
+// The full sweep is very long, narrow it with environment variables, e.g.
+// MSM_BENCH_LOG_N=10..=14 MSM_BENCH_WINDOWS=4..=12 MSM_BENCH_ALGORITHMS=pippenger,sid_pippenger MSM_BENCH_CURVES=mnt4-298 cargo bench --bench msm
+// or pass a Criterion filter such as "cargo bench --bench msm -- mnt4-298/n=1024/"
+// Median runtimes are written to summary.csv and summary.json in MSM_BENCH_SUMMARY_DIR (default target/msm-bench) to compare versions
+use criterion::{black_box, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::json;
+use std::env;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Instant;
+use msm::algorithm::{algorithms, MsmConfig};
+use msm::batch_affine::SwCurveGroup;
+use msm::tuning::{candidate_windows, generate_points};
+
+const CURVES: [&str; 4] = ["mnt4-298", "mnt4-753", "mnt6-298", "mnt6-753"];
+
+// Parts of the sweep to run, every dimension defaults to the full range
+struct Sweep {
+    log_n: RangeInclusive<u32>,
+    windows: RangeInclusive<usize>,
+    algorithms: Option<Vec<String>>,
+    curves: Vec<String>,
+}
+
+impl Sweep {
+    fn from_env() -> Self {
+        Sweep {
+            log_n: range_from_env("MSM_BENCH_LOG_N", 4..=20),
+            windows: range_from_env("MSM_BENCH_WINDOWS", 1..=16),
+            algorithms: list_from_env("MSM_BENCH_ALGORITHMS"),
+            curves: list_from_env("MSM_BENCH_CURVES").unwrap_or_else(|| CURVES.iter().map(|curve| curve.to_string()).collect()),
+        }
+    }
+
+    fn includes_algorithm(&self, name: &str) -> bool {
+        self.algorithms.as_ref().is_none_or(|algorithms| algorithms.iter().any(|algorithm| algorithm == name))
+    }
+}
+
+// Range given as "a..=b" or a single value "a"
+fn range_from_env<T: std::str::FromStr + Copy>(name: &str, default: RangeInclusive<T>) -> RangeInclusive<T> {
+    let Ok(value) = env::var(name) else {
+        return default;
+    };
+    let parse = |bound: &str| bound.trim().parse().unwrap_or_else(|_| panic!("{} should be a range such as 4..=12, got {:?}", name, value));
+    match value.split_once("..=") {
+        Some((start, end)) => parse(start)..=parse(end),
+        None => parse(&value)..=parse(&value),
+    }
+}
+
+// Comma separated list
+fn list_from_env(name: &str) -> Option<Vec<String>> {
+    env::var(name).ok().map(|value| value.split(',').map(|item| item.trim().to_string()).collect())
+}
+
+// Median runtime of one benchmark
+struct Measurement {
+    curve: String,
+    algorithm: &'static str,
+    num_points: usize,
+    window_size: Option<usize>,
+    median_ns: f64,
+}
+
+impl Measurement {
+    fn points_per_second(&self) -> f64 {
+        self.num_points as f64 / (self.median_ns / 1e9)
+    }
+}
+
+fn generate_scalars(num_scalars: usize, rng: &mut StdRng) -> Vec<u32> {
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+fn bench_curve<G: SwCurveGroup>(criterion: &mut Criterion, curve: &str, sweep: &Sweep, measurements: &mut Vec<Measurement>) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for log_n in sweep.log_n.clone() {
+        let num_points = 1usize << log_n;
+        let points = generate_points::<G>(num_points, &mut rng);
+        let scalars = generate_scalars(num_points, &mut rng);
+
+        let mut group = criterion.benchmark_group(format!("{}/n={}", curve, num_points));
+        group.throughput(Throughput::Elements(num_points as u64));
+        group.sample_size(10);
+
+        for algorithm in algorithms::<G>().into_iter().filter(|algorithm| sweep.includes_algorithm(algorithm.name())) {
+            // Algorithms without a window are measured once per input size, and windows outside an algorithm's range are skipped
+            for window_size in candidate_windows(algorithm.as_ref(), &sweep.windows) {
+                let config = MsmConfig { window_size, ..MsmConfig::default() };
+                let id = BenchmarkId::new(algorithm.name(), window_size.map_or("auto".to_string(), |c| format!("c={}", c)));
+                let mut per_iteration_ns = Vec::new();
+                group.bench_function(id, |bencher| bencher.iter_custom(|iterations| {
+                    let start = Instant::now();
+                    for _ in 0..iterations {
+                        black_box(algorithm.compute(black_box(&points), black_box(&scalars), &config));
+                    }
+                    let elapsed = start.elapsed();
+                    per_iteration_ns.push(elapsed.as_nanos() as f64 / iterations as f64);
+                    elapsed
+                }));
+
+                // Benchmarks excluded by a Criterion filter never run and are left out of the summary
+                if !per_iteration_ns.is_empty() {
+                    per_iteration_ns.sort_by(f64::total_cmp);
+                    measurements.push(Measurement {
+                        curve: curve.to_string(),
+                        algorithm: algorithm.name(),
+                        num_points,
+                        window_size,
+                        median_ns: per_iteration_ns[per_iteration_ns.len() / 2],
+                    });
+                }
+            }
+        }
+
+        group.finish();
+    }
+}
+
+fn write_summaries(measurements: &[Measurement]) -> std::io::Result<PathBuf> {
+    let directory = env::var("MSM_BENCH_SUMMARY_DIR").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from("target/msm-bench"));
+    fs::create_dir_all(&directory)?;
+
+    let mut csv = String::from("curve,algorithm,num_points,window_size,median_ns,points_per_second\n");
+    for measurement in measurements {
+        csv.push_str(&format!(
+            "{},{},{},{},{:.0},{:.0}\n",
+            measurement.curve,
+            measurement.algorithm,
+            measurement.num_points,
+            measurement.window_size.map_or(String::new(), |c| c.to_string()),
+            measurement.median_ns,
+            measurement.points_per_second(),
+        ));
+    }
+    fs::write(directory.join("summary.csv"), csv)?;
+
+    let json: Vec<_> = measurements.iter().map(|measurement| json!({
+        "curve": measurement.curve,
+        "algorithm": measurement.algorithm,
+        "num_points": measurement.num_points,
+        "window_size": measurement.window_size,
+        "median_ns": measurement.median_ns,
+        "points_per_second": measurement.points_per_second(),
+    })).collect();
+    fs::write(directory.join("summary.json"), serde_json::to_string_pretty(&json)?)?;
+
+    Ok(directory)
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    let sweep = Sweep::from_env();
+    let mut measurements = Vec::new();
+
+    for curve in &sweep.curves {
+        match curve.as_str() {
+            "mnt4-298" => bench_curve::<ark_mnt4_298::G1Projective>(&mut criterion, curve, &sweep, &mut measurements),
+            "mnt4-753" => bench_curve::<ark_mnt4_753::G1Projective>(&mut criterion, curve, &sweep, &mut measurements),
+            "mnt6-298" => bench_curve::<ark_mnt6_298::G1Projective>(&mut criterion, curve, &sweep, &mut measurements),
+            "mnt6-753" => bench_curve::<ark_mnt6_753::G1Projective>(&mut criterion, curve, &sweep, &mut measurements),
+            _ => panic!("Unknown curve {:?}, expected one of {:?}", curve, CURVES),
+        }
+    }
+
+    criterion.final_summary();
+
+    match write_summaries(&measurements) {
+        Ok(directory) => println!("Wrote {} measurements to {}", measurements.len(), directory.display()),
+        Err(error) => eprintln!("Failed to write the benchmark summaries: {}", error),
+    }
+}
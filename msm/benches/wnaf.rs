@@ -0,0 +1,53 @@
+// Compare interleaved wNAF against SID Pippenger for medium-sized inputs, run with "cargo bench --bench wnaf"
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_std::{test_rng, UniformRand};
+use std::time::{Duration, Instant};
+use msm::sid_pippenger::sid_pippenger_field;
+use msm::wnaf_msm::wnaf_msm_field;
+use msm::window::{optimal_window_size, wnaf_window_size, PippengerVariant};
+
+// Number of timed runs per measurement, the median is reported
+const NUM_RUNS: usize = 5;
+
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+// Median runtime of f over NUM_RUNS runs
+fn time(f: impl Fn() -> G1Projective) -> Duration {
+    let mut durations: Vec<Duration> = (0..NUM_RUNS).map(|_| {
+        let start = Instant::now();
+        let _ = std::hint::black_box(f());
+        start.elapsed()
+    }).collect();
+    durations.sort();
+    durations[NUM_RUNS / 2]
+}
+
+fn main() {
+    let scalar_bits = 298;
+    println!("{:>6} {:>12} {:>16} {:>12} {:>16}", "n", "wnaf window", "wnaf_msm", "sid window", "sid_pippenger");
+
+    for log_n in 4..=10 {
+        let num_points = 1 << log_n;
+        let points = generate_points(num_points);
+        let scalars = generate_field_scalars(num_points);
+
+        let wnaf_window = wnaf_window_size(scalar_bits);
+        let sid_window = optimal_window_size(num_points, scalar_bits, PippengerVariant::Sid);
+        println!(
+            "{:>6} {:>12} {:>16?} {:>12} {:>16?}",
+            num_points,
+            wnaf_window,
+            time(|| wnaf_msm_field(&points, &scalars, wnaf_window)),
+            sid_window,
+            time(|| sid_pippenger_field(&points, &scalars, sid_window)),
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_window_size_range, MsmError, MAX_FLAT_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::bucket::bucket_running_sum;
+use crate::scalar::{bigint_window, num_partitions};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+
+// Streaming Pippenger MSM, point-scalar pairs are added to per-window buckets as they arrive
+// so data streamed from disk or generated on the fly never has to be held in memory at once
+pub struct MsmAccumulator<G: CurveGroup> {
+    window_size: usize,
+    scalar_bits: usize,
+    num_windows: usize,
+    num_pairs: usize,
+    // buckets[k][v - 1] sums every point whose window k has value v
+    // A window's buckets are only allocated once a pair has a non-zero value in it
+    buckets: Vec<Vec<G>>,
+}
+
+impl<G: CurveGroup> MsmAccumulator<G> {
+    // Accumulator for full-width scalar field elements
+    pub fn new(window_size: usize) -> Self {
+        Self::with_scalar_bits(window_size, G::ScalarField::MODULUS_BIT_SIZE as usize)
+    }
+
+    // Accumulator for scalars of at most scalar_bits bits, e.g. 32 for u32 scalars
+    // Narrower scalars need fewer windows, so fewer buckets are kept and finalize needs fewer doublings
+    pub fn with_scalar_bits(window_size: usize, scalar_bits: usize) -> Self {
+        if let Err(error) = check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE) {
+            panic!("{}", error);
+        }
+
+        let num_windows = num_partitions(scalar_bits, window_size);
+        MsmAccumulator {
+            window_size,
+            scalar_bits,
+            num_windows,
+            num_pairs: 0,
+            buckets: vec![Vec::new(); num_windows],
+        }
+    }
+
+    // Fallible variant of new that rejects unsupported window sizes instead of panicking
+    pub fn try_new(window_size: usize) -> Result<Self, MsmError> {
+        Self::try_with_scalar_bits(window_size, G::ScalarField::MODULUS_BIT_SIZE as usize)
+    }
+
+    // Fallible variant of with_scalar_bits that rejects unsupported window sizes instead of panicking
+    // Every window keeps 2^window_size - 1 buckets, so windows are capped at MAX_FLAT_WINDOW_SIZE like the flat bucket variants
+    pub fn try_with_scalar_bits(window_size: usize, scalar_bits: usize) -> Result<Self, MsmError> {
+        check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+        Ok(Self::with_scalar_bits(window_size, scalar_bits))
+    }
+
+    fn num_buckets_for(window_size: usize) -> usize {
+        (1 << window_size) - 1
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    // Widest scalars the accumulator covers
+    pub fn scalar_bits(&self) -> usize {
+        self.scalar_bits
+    }
+
+    pub fn num_windows(&self) -> usize {
+        self.num_windows
+    }
+
+    // Number of point-scalar pairs added so far
+    pub fn len(&self) -> usize {
+        self.num_pairs
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_pairs == 0
+    }
+
+    // Add a single pair with a 32-bit scalar
+    pub fn push(&mut self, point: G, scalar: u32) {
+        if let Err(error) = self.check_scalar_bits(32) {
+            panic!("{}", error);
+        }
+
+        let mask = (1u32 << self.window_size) - 1;
+        self.accumulate(point, |bit_index| if bit_index >= 32 { 0 } else { (scalar >> bit_index) & mask });
+    }
+
+    // Add a single pair with a full-width field scalar
+    pub fn push_field(&mut self, point: G, scalar: &G::ScalarField) {
+        if let Err(error) = self.check_scalar_bits(G::ScalarField::MODULUS_BIT_SIZE as usize) {
+            panic!("{}", error);
+        }
+
+        let bigint = scalar.into_bigint();
+        let window_size = self.window_size;
+        self.accumulate(point, |bit_index| bigint_window(&bigint, bit_index, window_size));
+    }
+
+    // Add a chunk of pairs held in slices
+    pub fn push_chunk(&mut self, points: &[G], scalars: &[u32]) {
+        assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+        self.extend(points.iter().copied().zip(scalars.iter().copied()));
+    }
+
+    // Add a chunk of pairs with full-width field scalars held in slices
+    pub fn push_chunk_field(&mut self, points: &[G], scalars: &[G::ScalarField]) {
+        assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+        for (&point, scalar) in points.iter().zip(scalars) {
+            self.push_field(point, scalar);
+        }
+    }
+
+    // Fallible variant of push that rejects scalars wider than the accumulator instead of panicking
+    pub fn try_push(&mut self, point: G, scalar: u32) -> Result<(), MsmError> {
+        self.check_scalar_bits(32)?;
+        self.push(point, scalar);
+        Ok(())
+    }
+
+    // Fallible variant of push_field that rejects scalars wider than the accumulator instead of panicking
+    pub fn try_push_field(&mut self, point: G, scalar: &G::ScalarField) -> Result<(), MsmError> {
+        self.check_scalar_bits(G::ScalarField::MODULUS_BIT_SIZE as usize)?;
+        self.push_field(point, scalar);
+        Ok(())
+    }
+
+    // Fallible variant of push_chunk that validates its inputs instead of panicking
+    pub fn try_push_chunk(&mut self, points: &[G], scalars: &[u32]) -> Result<(), MsmError> {
+        check_lengths(points.len(), scalars.len())?;
+        self.check_scalar_bits(32)?;
+        self.push_chunk(points, scalars);
+        Ok(())
+    }
+
+    // Fallible variant of push_chunk_field that validates its inputs instead of panicking
+    pub fn try_push_chunk_field(&mut self, points: &[G], scalars: &[G::ScalarField]) -> Result<(), MsmError> {
+        check_lengths(points.len(), scalars.len())?;
+        self.check_scalar_bits(G::ScalarField::MODULUS_BIT_SIZE as usize)?;
+        self.push_chunk_field(points, scalars);
+        Ok(())
+    }
+
+    // Ensure scalars of scalar_bits bits fit in the windows of the accumulator
+    fn check_scalar_bits(&self, scalar_bits: usize) -> Result<(), MsmError> {
+        if scalar_bits > self.scalar_bits {
+            return Err(MsmError::ScalarTooWide { scalar_bits, max_bits: self.scalar_bits });
+        }
+        Ok(())
+    }
+
+    // Add every pair yielded by an iterator
+    pub fn extend<I: IntoIterator<Item = (G, u32)>>(&mut self, pairs: I) {
+        for (point, scalar) in pairs {
+            self.push(point, scalar);
+        }
+    }
+
+    // Add every pair with a full-width field scalar yielded by an iterator
+    pub fn extend_field<I: IntoIterator<Item = (G, G::ScalarField)>>(&mut self, pairs: I) {
+        for (point, scalar) in pairs {
+            self.push_field(point, &scalar);
+        }
+    }
+
+    // Fold the buckets of another accumulator into this one, so separate streams can be accumulated on separate threads
+    pub fn merge(&mut self, other: &Self) {
+        if let Err(error) = self.check_mergeable(other) {
+            panic!("{}", error);
+        }
+
+        for (window, other_window) in self.buckets.iter_mut().zip(&other.buckets) {
+            if window.is_empty() {
+                window.clone_from(other_window);
+            } else if !other_window.is_empty() {
+                for (bucket, &other_bucket) in window.iter_mut().zip(other_window) {
+                    *bucket = add_points(*bucket, other_bucket);
+                }
+            }
+        }
+        self.num_pairs += other.num_pairs;
+    }
+
+    // Fallible variant of merge that rejects accumulators with different windows instead of panicking
+    pub fn try_merge(&mut self, other: &Self) -> Result<(), MsmError> {
+        self.check_mergeable(other)?;
+        self.merge(other);
+        Ok(())
+    }
+
+    // Buckets can only be added when both accumulators split scalars into the same windows
+    fn check_mergeable(&self, other: &Self) -> Result<(), MsmError> {
+        if self.window_size != other.window_size || self.num_windows != other.num_windows {
+            return Err(MsmError::AccumulatorMismatch {
+                window_size: self.window_size,
+                num_windows: self.num_windows,
+                other_window_size: other.window_size,
+                other_num_windows: other.num_windows,
+            });
+        }
+        Ok(())
+    }
+
+    // Reduce the buckets of every window and combine the windows into the MSM result
+    pub fn finalize(self) -> G {
+        let mut final_result = G::zero();
+
+        // Iterating over each window in reverse to ensure doubling mimics scaling accurately
+        for window in self.buckets.iter().rev() {
+            // Double the final result window_size times to mimic scaling by 2^bit_index
+            for _ in 0..self.window_size {
+                final_result = double_point(final_result);
+            }
+            // Windows that never received a non-zero value were never allocated and contribute nothing
+            if !window.is_empty() {
+                final_result = add_points(final_result, bucket_running_sum(window));
+            }
+        }
+
+        final_result
+    }
+
+    // Add the point to the bucket of each of its non-zero windows, window_value(bit_index) gives the window starting at bit_index
+    fn accumulate(&mut self, point: G, window_value: impl Fn(usize) -> u32) {
+        let num_buckets = Self::num_buckets_for(self.window_size);
+
+        for (window, buckets) in self.buckets.iter_mut().enumerate() {
+            let value = window_value(window * self.window_size);
+            if value != 0 {
+                if buckets.is_empty() {
+                    *buckets = vec![G::zero(); num_buckets];
+                }
+                let bucket = value as usize - 1;
+                buckets[bucket] = add_points(buckets[bucket], point);
+            }
+        }
+        self.num_pairs += 1;
+    }
+}
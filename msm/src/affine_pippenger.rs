@@ -0,0 +1,86 @@
+use crate::operations::{add_mixed, add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::pippenger::{partition_msm, partition_msm_field, MsmPartition};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::AffineRepr;
+use ark_ff::Zero;
+use std::collections::HashMap;
+
+// Main function for Pippenger with affine input points, as stored in SRS and commitment keys
+pub fn affine_pippenger<A: AffineRepr>(points: &[A], scalars: &[u32], window_size: usize) -> A::Group {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = partition_msm(scalars, window_size);
+    affine_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Main function for affine_pippenger with full-width field scalars
+pub fn affine_pippenger_field<A: AffineRepr>(points: &[A], scalars: &[A::ScalarField], window_size: usize) -> A::Group {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = partition_msm_field(scalars, window_size);
+    affine_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of affine_pippenger that validates its inputs instead of panicking
+pub fn try_affine_pippenger<A: AffineRepr>(points: &[A], scalars: &[u32], window_size: usize) -> Result<A::Group, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<A::Group>(32)?;
+    Ok(affine_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of affine_pippenger_field that validates its inputs instead of panicking
+pub fn try_affine_pippenger_field<A: AffineRepr>(points: &[A], scalars: &[A::ScalarField], window_size: usize) -> Result<A::Group, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(affine_pippenger_field(points, scalars, window_size))
+}
+
+pub fn affine_compute_msm_for_partition<A: AffineRepr>(partition: &MsmPartition, points: &[A], window_size: usize) -> A::Group {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
+    let mut buckets: HashMap<u32, Vec<usize>> = HashMap::new();
+    for (index, &value) in partition.window_values.iter().enumerate() {
+        if value != 0 {
+            buckets.entry(value).or_default().push(index);
+        }
+    }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
+
+    let max_scalar_value = (1 << window_size) - 1;
+    let mut msm_result = A::Group::zero();
+    let mut temp = A::Group::zero();
+
+    for scalar_value in (1..=max_scalar_value).rev() {
+        if let Some(indexes) = buckets.get(&scalar_value) {
+            // Every point added to a bucket is affine, so the bucket sums only need mixed additions
+            let sum_of_points: A::Group = indexes.iter()
+                .fold(A::Group::zero(), |sum, &i| add_mixed(sum, points[i]));
+            temp = add_points(temp, sum_of_points);
+        }
+        msm_result = add_points(msm_result, temp);
+    }
+
+    msm_result
+}
+
+pub fn affine_combine_partitioned_msm<A: AffineRepr>(partitions: &[MsmPartition], points: &[A], window_size: usize) -> A::Group {
+    let mut final_result = A::Group::zero();
+
+    // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
+    for partition in partitions.iter().rev() {
+        let partition_msm = affine_compute_msm_for_partition(partition, points, window_size);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
+
+        // Double the final result window_size times to mimic scaling by 2^bit_index
+        for _ in 0..window_size {
+            final_result = double_point(final_result);
+        }
+
+        // Adding the partition MSM to the final result
+        final_result = add_points(final_result, partition_msm);
+    }
+
+    final_result
+}
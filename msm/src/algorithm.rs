@@ -0,0 +1,455 @@
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use crate::error::{MsmError, MAX_FLAT_WINDOW_SIZE, MAX_WINDOW_SIZE, MIN_SID_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::counting::{count_operations, OperationCounts};
+use crate::profiling::{profile, StageTimings};
+use crate::thread_pool::{install, try_install};
+use crate::tuning::tuned_entry;
+use crate::window::{optimal_window_size, straus_window_size, wnaf_window_size, PippengerVariant, MAX_STRAUS_WINDOW_SIZE};
+use std::ops::RangeInclusive;
+use crate::naive::{naive_msm, naive_msm_field, try_naive_msm, try_naive_msm_field};
+use crate::trivial::{trivial_msm, trivial_msm_field, try_trivial_msm, try_trivial_msm_field};
+use crate::bos_coster::{bos_coster, bos_coster_field, try_bos_coster, try_bos_coster_field};
+use crate::pippenger::{pippenger, pippenger_field, try_pippenger, try_pippenger_field};
+use crate::parallel_pippenger::{parallel_pippenger, parallel_pippenger_field, try_parallel_pippenger, try_parallel_pippenger_field};
+use crate::subsum_pippenger::{subsum_pippenger, subsum_pippenger_field, try_subsum_pippenger, try_subsum_pippenger_field};
+use crate::sid_pippenger::{sid_pippenger, sid_pippenger_field, try_sid_pippenger, try_sid_pippenger_field};
+use crate::booth::{booth_pippenger, booth_pippenger_field, try_booth_pippenger, try_booth_pippenger_field};
+use crate::parallel_sid_pippenger::{parallel_sid_pippenger, parallel_sid_pippenger_field, try_parallel_sid_pippenger, try_parallel_sid_pippenger_field};
+use crate::parallel_subsum_pippenger::{parallel_subsum_pippenger, parallel_subsum_pippenger_field, try_parallel_subsum_pippenger, try_parallel_subsum_pippenger_field};
+use crate::sid_subsum_pippenger::{sid_subsum_pippenger, sid_subsum_pippenger_field, try_sid_subsum_pippenger, try_sid_subsum_pippenger_field};
+use crate::parallel_sid_subsum_pippenger::{parallel_sid_subsum_pippenger, parallel_sid_subsum_pippenger_field, try_parallel_sid_subsum_pippenger, try_parallel_sid_subsum_pippenger_field};
+use crate::chunked_pippenger::{chunked_pippenger, chunked_pippenger_field, try_chunked_pippenger, try_chunked_pippenger_field};
+use crate::hybrid_pippenger::{hybrid_pippenger, hybrid_pippenger_field, try_hybrid_pippenger, try_hybrid_pippenger_field};
+use crate::batch_affine::SwCurveGroup;
+use crate::batch_affine_pippenger::{batch_affine_pippenger, batch_affine_pippenger_field, try_batch_affine_pippenger, try_batch_affine_pippenger_field};
+use crate::sid_batch_affine_pippenger::{sid_batch_affine_pippenger, sid_batch_affine_pippenger_field, try_sid_batch_affine_pippenger, try_sid_batch_affine_pippenger_field};
+use crate::flat_pippenger::{flat_pippenger, flat_pippenger_field, try_flat_pippenger, try_flat_pippenger_field};
+use crate::flat_sid_pippenger::{flat_sid_pippenger, flat_sid_pippenger_field, try_flat_sid_pippenger, try_flat_sid_pippenger_field};
+use crate::straus::{straus, straus_field, try_straus, try_straus_field};
+use crate::wnaf_msm::{wnaf_msm, wnaf_msm_field, try_wnaf_msm, try_wnaf_msm_field};
+
+// Settings shared by every algorithm, algorithms without windows ignore window_size
+// When window_size is None the window is chosen automatically from the input size
+// When num_threads is None the parallel variants run on the global rayon pool
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MsmConfig {
+    pub window_size: Option<usize>,
+    pub num_threads: Option<usize>,
+}
+
+impl MsmConfig {
+    // Window size to use, falling back to the optimal window when none was supplied
+    pub fn window_size_for(&self, num_points: usize, scalar_bits: usize, variant: PippengerVariant) -> usize {
+        self.window_size.unwrap_or_else(|| optimal_window_size(num_points, scalar_bits, variant))
+    }
+}
+
+// Common interface implemented by every MSM algorithm in the suite
+pub trait MsmAlgorithm<G: CurveGroup>: Send + Sync {
+    // Name used to select the algorithm at runtime
+    fn name(&self) -> &'static str;
+
+    // Window sizes the algorithm accepts, None for algorithms without a window
+    fn window_range(&self) -> Option<RangeInclusive<usize>>;
+
+    // MSM with 32-bit scalars
+    fn compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> G;
+
+    // MSM with full-width field scalars
+    fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> G;
+
+    // Fallible MSM with 32-bit scalars that validates its inputs instead of panicking
+    fn try_compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> Result<G, MsmError>;
+
+    // Fallible MSM with full-width field scalars that validates its inputs instead of panicking
+    fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> Result<G, MsmError>;
+
+    // MSM with 32-bit scalars, returned with the time spent in each stage when built with the profiling feature
+    fn compute_with_timings(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> (G, StageTimings) {
+        profile(|| self.compute(points, scalars, config))
+    }
+
+    // MSM with full-width field scalars, returned with the time spent in each stage when built with the profiling feature
+    fn compute_field_with_timings(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> (G, StageTimings) {
+        profile(|| self.compute_field(points, scalars, config))
+    }
+
+    // MSM with 32-bit scalars, returned with the number of group operations of each kind it performed
+    fn compute_with_counts(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> (G, OperationCounts) {
+        count_operations(|| self.compute(points, scalars, config))
+    }
+
+    // MSM with full-width field scalars, returned with the number of group operations of each kind it performed
+    fn compute_field_with_counts(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> (G, OperationCounts) {
+        count_operations(|| self.compute_field(points, scalars, config))
+    }
+}
+
+pub struct Naive;
+pub struct Trivial;
+pub struct BosCoster;
+pub struct Pippenger;
+pub struct ParallelPippenger;
+pub struct SubsumPippenger;
+pub struct SidPippenger;
+pub struct ParallelSidPippenger;
+pub struct ParallelSubsumPippenger;
+pub struct SidSubsumPippenger;
+pub struct ParallelSidSubsumPippenger;
+pub struct ChunkedPippenger;
+pub struct HybridPippenger;
+pub struct BatchAffinePippenger;
+pub struct SidBatchAffinePippenger;
+pub struct FlatPippenger;
+pub struct FlatSidPippenger;
+pub struct Straus;
+pub struct Wnaf;
+pub struct BoothPippenger;
+pub struct Auto;
+
+impl<G: CurveGroup> MsmAlgorithm<G> for Naive {
+    fn name(&self) -> &'static str {
+        "naive"
+    }
+
+    fn window_range(&self) -> Option<RangeInclusive<usize>> {
+        None
+    }
+
+    fn compute(&self, points: &[G], scalars: &[u32], _config: &MsmConfig) -> G {
+        naive_msm(points, scalars)
+    }
+
+    fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], _config: &MsmConfig) -> G {
+        naive_msm_field(points, scalars)
+    }
+
+    fn try_compute(&self, points: &[G], scalars: &[u32], _config: &MsmConfig) -> Result<G, MsmError> {
+        try_naive_msm(points, scalars)
+    }
+
+    fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], _config: &MsmConfig) -> Result<G, MsmError> {
+        try_naive_msm_field(points, scalars)
+    }
+}
+
+impl<G: CurveGroup> MsmAlgorithm<G> for Trivial {
+    fn name(&self) -> &'static str {
+        "trivial"
+    }
+
+    fn window_range(&self) -> Option<RangeInclusive<usize>> {
+        None
+    }
+
+    fn compute(&self, points: &[G], scalars: &[u32], _config: &MsmConfig) -> G {
+        trivial_msm(points, scalars)
+    }
+
+    fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], _config: &MsmConfig) -> G {
+        trivial_msm_field(points, scalars)
+    }
+
+    fn try_compute(&self, points: &[G], scalars: &[u32], _config: &MsmConfig) -> Result<G, MsmError> {
+        try_trivial_msm(points, scalars)
+    }
+
+    fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], _config: &MsmConfig) -> Result<G, MsmError> {
+        try_trivial_msm_field(points, scalars)
+    }
+}
+
+impl<G: CurveGroup> MsmAlgorithm<G> for BosCoster {
+    fn name(&self) -> &'static str {
+        "bos_coster"
+    }
+
+    fn window_range(&self) -> Option<RangeInclusive<usize>> {
+        None
+    }
+
+    fn compute(&self, points: &[G], scalars: &[u32], _config: &MsmConfig) -> G {
+        bos_coster(points, scalars)
+    }
+
+    fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], _config: &MsmConfig) -> G {
+        bos_coster_field(points, scalars)
+    }
+
+    fn try_compute(&self, points: &[G], scalars: &[u32], _config: &MsmConfig) -> Result<G, MsmError> {
+        try_bos_coster(points, scalars)
+    }
+
+    fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], _config: &MsmConfig) -> Result<G, MsmError> {
+        try_bos_coster_field(points, scalars)
+    }
+}
+
+// Parallel variants run on the pool sized by config.num_threads, sequential variants run on the calling thread
+fn run<R: Send>(config: &MsmConfig, parallel: bool, f: impl FnOnce() -> R + Send) -> R {
+    if parallel {
+        install(config.num_threads, f)
+    } else {
+        f()
+    }
+}
+
+// Fallible variant of run that returns an error when the pool cannot be built
+fn try_run<R: Send>(config: &MsmConfig, parallel: bool, f: impl FnOnce() -> Result<R, MsmError> + Send) -> Result<R, MsmError> {
+    if parallel {
+        try_install(config.num_threads, f)?
+    } else {
+        f()
+    }
+}
+
+// The Pippenger variants only differ in the functions they forward to and the curve groups they support
+macro_rules! impl_windowed_algorithm {
+    ($algorithm:ident, $name:expr, $function:ident, $field_function:ident, $try_function:ident, $try_field_function:ident, $variant:expr, $window_range:expr, $parallel:expr) => {
+        impl_windowed_algorithm!(CurveGroup; $algorithm, $name, $function, $field_function, $try_function, $try_field_function, $variant, $window_range, $parallel);
+    };
+    ($bound:path; $algorithm:ident, $name:expr, $function:ident, $field_function:ident, $try_function:ident, $try_field_function:ident, $variant:expr, $window_range:expr, $parallel:expr) => {
+        impl<G: $bound> MsmAlgorithm<G> for $algorithm {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn window_range(&self) -> Option<RangeInclusive<usize>> {
+                Some($window_range)
+            }
+
+            fn compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> G {
+                let window_size = config.window_size_for(points.len(), 32, $variant);
+                run(config, $parallel, || $function(points, scalars, window_size))
+            }
+
+            fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> G {
+                let window_size = config.window_size_for(points.len(), G::ScalarField::MODULUS_BIT_SIZE as usize, $variant);
+                run(config, $parallel, || $field_function(points, scalars, window_size))
+            }
+
+            fn try_compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> Result<G, MsmError> {
+                let window_size = config.window_size_for(points.len(), 32, $variant);
+                try_run(config, $parallel, || $try_function(points, scalars, window_size))
+            }
+
+            fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> Result<G, MsmError> {
+                let window_size = config.window_size_for(points.len(), G::ScalarField::MODULUS_BIT_SIZE as usize, $variant);
+                try_run(config, $parallel, || $try_field_function(points, scalars, window_size))
+            }
+        }
+    };
+}
+
+impl_windowed_algorithm!(Pippenger, "pippenger", pippenger, pippenger_field, try_pippenger, try_pippenger_field, PippengerVariant::Standard, MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE, false);
+impl_windowed_algorithm!(ParallelPippenger, "parallel_pippenger", parallel_pippenger, parallel_pippenger_field, try_parallel_pippenger, try_parallel_pippenger_field, PippengerVariant::Standard, MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE, true);
+impl_windowed_algorithm!(SubsumPippenger, "subsum_pippenger", subsum_pippenger, subsum_pippenger_field, try_subsum_pippenger, try_subsum_pippenger_field, PippengerVariant::Subsum, MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE, false);
+impl_windowed_algorithm!(SidPippenger, "sid_pippenger", sid_pippenger, sid_pippenger_field, try_sid_pippenger, try_sid_pippenger_field, PippengerVariant::Sid, MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE, false);
+impl_windowed_algorithm!(ParallelSidPippenger, "parallel_sid_pippenger", parallel_sid_pippenger, parallel_sid_pippenger_field, try_parallel_sid_pippenger, try_parallel_sid_pippenger_field, PippengerVariant::Sid, MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE, true);
+impl_windowed_algorithm!(ParallelSubsumPippenger, "parallel_subsum_pippenger", parallel_subsum_pippenger, parallel_subsum_pippenger_field, try_parallel_subsum_pippenger, try_parallel_subsum_pippenger_field, PippengerVariant::Subsum, MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE, true);
+impl_windowed_algorithm!(SidSubsumPippenger, "sid_subsum_pippenger", sid_subsum_pippenger, sid_subsum_pippenger_field, try_sid_subsum_pippenger, try_sid_subsum_pippenger_field, PippengerVariant::SidSubsum, MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE, false);
+impl_windowed_algorithm!(ParallelSidSubsumPippenger, "parallel_sid_subsum_pippenger", parallel_sid_subsum_pippenger, parallel_sid_subsum_pippenger_field, try_parallel_sid_subsum_pippenger, try_parallel_sid_subsum_pippenger_field, PippengerVariant::SidSubsum, MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE, true);
+impl_windowed_algorithm!(ChunkedPippenger, "chunked_pippenger", chunked_pippenger, chunked_pippenger_field, try_chunked_pippenger, try_chunked_pippenger_field, PippengerVariant::Standard, MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE, true);
+impl_windowed_algorithm!(HybridPippenger, "hybrid_pippenger", hybrid_pippenger, hybrid_pippenger_field, try_hybrid_pippenger, try_hybrid_pippenger_field, PippengerVariant::Standard, MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE, true);
+impl_windowed_algorithm!(SwCurveGroup; BatchAffinePippenger, "batch_affine_pippenger", batch_affine_pippenger, batch_affine_pippenger_field, try_batch_affine_pippenger, try_batch_affine_pippenger_field, PippengerVariant::Standard, MIN_WINDOW_SIZE..=MAX_FLAT_WINDOW_SIZE, false);
+impl_windowed_algorithm!(SwCurveGroup; SidBatchAffinePippenger, "sid_batch_affine_pippenger", sid_batch_affine_pippenger, sid_batch_affine_pippenger_field, try_sid_batch_affine_pippenger, try_sid_batch_affine_pippenger_field, PippengerVariant::Sid, MIN_SID_WINDOW_SIZE..=MAX_FLAT_WINDOW_SIZE, false);
+impl_windowed_algorithm!(FlatPippenger, "flat_pippenger", flat_pippenger, flat_pippenger_field, try_flat_pippenger, try_flat_pippenger_field, PippengerVariant::Standard, MIN_WINDOW_SIZE..=MAX_FLAT_WINDOW_SIZE, false);
+impl_windowed_algorithm!(FlatSidPippenger, "flat_sid_pippenger", flat_sid_pippenger, flat_sid_pippenger_field, try_flat_sid_pippenger, try_flat_sid_pippenger_field, PippengerVariant::Sid, MIN_SID_WINDOW_SIZE..=MAX_FLAT_WINDOW_SIZE, false);
+impl_windowed_algorithm!(BoothPippenger, "booth_pippenger", booth_pippenger, booth_pippenger_field, try_booth_pippenger, try_booth_pippenger_field, PippengerVariant::Sid, MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE, false);
+
+impl<G: CurveGroup> MsmAlgorithm<G> for Straus {
+    fn name(&self) -> &'static str {
+        "straus"
+    }
+
+    // Tables of 2^(c-1) multiples per point are capped at MAX_STRAUS_WINDOW_SIZE
+    fn window_range(&self) -> Option<RangeInclusive<usize>> {
+        Some(MIN_WINDOW_SIZE..=MAX_STRAUS_WINDOW_SIZE)
+    }
+
+    fn compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> G {
+        straus(points, scalars, config.window_size.unwrap_or_else(|| straus_window_size(32)))
+    }
+
+    fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> G {
+        straus_field(points, scalars, config.window_size.unwrap_or_else(|| straus_window_size(G::ScalarField::MODULUS_BIT_SIZE as usize)))
+    }
+
+    fn try_compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> Result<G, MsmError> {
+        try_straus(points, scalars, config.window_size.unwrap_or_else(|| straus_window_size(32)))
+    }
+
+    fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> Result<G, MsmError> {
+        try_straus_field(points, scalars, config.window_size.unwrap_or_else(|| straus_window_size(G::ScalarField::MODULUS_BIT_SIZE as usize)))
+    }
+}
+
+impl<G: CurveGroup> MsmAlgorithm<G> for Wnaf {
+    fn name(&self) -> &'static str {
+        "wnaf"
+    }
+
+    // Tables of 2^(c-1) multiples per point are capped at MAX_STRAUS_WINDOW_SIZE
+    fn window_range(&self) -> Option<RangeInclusive<usize>> {
+        Some(MIN_SID_WINDOW_SIZE..=MAX_STRAUS_WINDOW_SIZE)
+    }
+
+    fn compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> G {
+        wnaf_msm(points, scalars, config.window_size.unwrap_or_else(|| wnaf_window_size(32)))
+    }
+
+    fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> G {
+        wnaf_msm_field(points, scalars, config.window_size.unwrap_or_else(|| wnaf_window_size(G::ScalarField::MODULUS_BIT_SIZE as usize)))
+    }
+
+    fn try_compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> Result<G, MsmError> {
+        try_wnaf_msm(points, scalars, config.window_size.unwrap_or_else(|| wnaf_window_size(32)))
+    }
+
+    fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> Result<G, MsmError> {
+        try_wnaf_msm_field(points, scalars, config.window_size.unwrap_or_else(|| wnaf_window_size(G::ScalarField::MODULUS_BIT_SIZE as usize)))
+    }
+}
+
+// Below this many points the Pippenger buckets are mostly empty, so the dispatcher uses the Straus method instead
+pub const STRAUS_MAX_POINTS: usize = 32;
+
+// Algorithm and settings the dispatcher runs for num_points points
+// A tuning profile installed for G decides when it has entries, with a window supplied in config taking precedence over the tuned one
+// Otherwise Straus is used below STRAUS_MAX_POINTS and Pippenger above
+// lookup resolves the tuned algorithm's name, the curve-generic dispatcher cannot run the batch-affine variants
+fn dispatch<G: CurveGroup>(num_points: usize, field_scalars: bool, config: &MsmConfig, lookup: fn(&str) -> Option<Box<dyn MsmAlgorithm<G>>>) -> (Box<dyn MsmAlgorithm<G>>, MsmConfig) {
+    // Entries naming an unknown algorithm or the dispatcher itself are ignored
+    let tuned = tuned_entry::<G>(num_points, field_scalars)
+        .and_then(|entry| lookup(&entry.algorithm).filter(|algorithm| algorithm.name() != "auto").map(|algorithm| (algorithm, entry.window_size)));
+    if let Some((algorithm, window_size)) = tuned {
+        return (algorithm, MsmConfig { window_size: config.window_size.or(window_size), ..*config });
+    }
+
+    let algorithm: Box<dyn MsmAlgorithm<G>> = if num_points < STRAUS_MAX_POINTS {
+        Box::new(Straus)
+    } else {
+        Box::new(Pippenger)
+    };
+    // A window sized for Pippenger is clamped to the range of the chosen algorithm, since wider Straus tables would exhaust memory
+    let window_size = config.window_size.map(|window_size| match algorithm.window_range() {
+        Some(range) => window_size.clamp(*range.start(), *range.end()),
+        None => window_size,
+    });
+    (algorithm, MsmConfig { window_size, ..*config })
+}
+
+// Pick the algorithm the dispatcher runs for num_points points with u32 scalars
+pub fn select_algorithm<G: CurveGroup>(num_points: usize) -> Box<dyn MsmAlgorithm<G>> {
+    dispatch::<G>(num_points, false, &MsmConfig::default(), get_curve_algorithm::<G>).0
+}
+
+// Dispatchers forward to the algorithm and window picked for the input size
+macro_rules! impl_dispatcher {
+    ($bound:path; $dispatcher:ident, $lookup:ident) => {
+        impl<G: $bound> MsmAlgorithm<G> for $dispatcher {
+            fn name(&self) -> &'static str {
+                "auto"
+            }
+
+            fn window_range(&self) -> Option<RangeInclusive<usize>> {
+                None
+            }
+
+            fn compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> G {
+                let (algorithm, config) = dispatch::<G>(points.len(), false, config, $lookup::<G>);
+                algorithm.compute(points, scalars, &config)
+            }
+
+            fn compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> G {
+                let (algorithm, config) = dispatch::<G>(points.len(), true, config, $lookup::<G>);
+                algorithm.compute_field(points, scalars, &config)
+            }
+
+            fn try_compute(&self, points: &[G], scalars: &[u32], config: &MsmConfig) -> Result<G, MsmError> {
+                let (algorithm, config) = dispatch::<G>(points.len(), false, config, $lookup::<G>);
+                algorithm.try_compute(points, scalars, &config)
+            }
+
+            fn try_compute_field(&self, points: &[G], scalars: &[G::ScalarField], config: &MsmConfig) -> Result<G, MsmError> {
+                let (algorithm, config) = dispatch::<G>(points.len(), true, config, $lookup::<G>);
+                algorithm.try_compute_field(points, scalars, &config)
+            }
+        }
+    };
+}
+
+// Auto runs on any curve group, so tuned entries naming a batch-affine variant fall back to the heuristic
+impl_dispatcher!(CurveGroup; Auto, get_curve_algorithm);
+
+// The registry's "auto" on short Weierstrass curve groups, which can also follow tuned entries naming a batch-affine variant
+struct RegistryAuto;
+impl_dispatcher!(SwCurveGroup; RegistryAuto, get_algorithm);
+
+// Every algorithm in the suite that runs on any curve group, in registry order, keep it in step with algorithms()
+fn curve_algorithms<G: CurveGroup>() -> Vec<Box<dyn MsmAlgorithm<G>>> {
+    vec![
+        Box::new(Naive),
+        Box::new(Trivial),
+        Box::new(BosCoster),
+        Box::new(Pippenger),
+        Box::new(ParallelPippenger),
+        Box::new(SubsumPippenger),
+        Box::new(SidPippenger),
+        Box::new(ParallelSidPippenger),
+        Box::new(ParallelSubsumPippenger),
+        Box::new(SidSubsumPippenger),
+        Box::new(ParallelSidSubsumPippenger),
+        Box::new(ChunkedPippenger),
+        Box::new(HybridPippenger),
+        Box::new(FlatPippenger),
+        Box::new(FlatSidPippenger),
+        Box::new(Straus),
+        Box::new(Wnaf),
+        Box::new(BoothPippenger),
+    ]
+}
+
+// Select an algorithm that runs on any curve group by name, the dispatcher and the batch-affine variants are not included
+fn get_curve_algorithm<G: CurveGroup>(name: &str) -> Option<Box<dyn MsmAlgorithm<G>>> {
+    curve_algorithms().into_iter().find(|algorithm| algorithm.name() == name)
+}
+
+// Registry of every algorithm in the suite
+// The batch-affine variants need affine coordinates, so the registry is limited to short Weierstrass curve groups such as the MNT curves
+pub fn algorithms<G: SwCurveGroup>() -> Vec<Box<dyn MsmAlgorithm<G>>> {
+    vec![
+        Box::new(Naive),
+        Box::new(Trivial),
+        Box::new(BosCoster),
+        Box::new(Pippenger),
+        Box::new(ParallelPippenger),
+        Box::new(SubsumPippenger),
+        Box::new(SidPippenger),
+        Box::new(ParallelSidPippenger),
+        Box::new(ParallelSubsumPippenger),
+        Box::new(SidSubsumPippenger),
+        Box::new(ParallelSidSubsumPippenger),
+        Box::new(ChunkedPippenger),
+        Box::new(HybridPippenger),
+        Box::new(BatchAffinePippenger),
+        Box::new(SidBatchAffinePippenger),
+        Box::new(FlatPippenger),
+        Box::new(FlatSidPippenger),
+        Box::new(Straus),
+        Box::new(Wnaf),
+        Box::new(BoothPippenger),
+        Box::new(RegistryAuto),
+    ]
+}
+
+// Names of every registered algorithm, in registry order
+pub fn algorithm_names<G: SwCurveGroup>() -> Vec<&'static str> {
+    algorithms::<G>().iter().map(|algorithm| algorithm.name()).collect()
+}
+
+// Select an algorithm by name at runtime, returns None for unknown names
+pub fn get_algorithm<G: SwCurveGroup>(name: &str) -> Option<Box<dyn MsmAlgorithm<G>>> {
+    algorithms().into_iter().find(|algorithm| algorithm.name() == name)
+}
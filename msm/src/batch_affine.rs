@@ -0,0 +1,113 @@
+use crate::counting::{record, Operation};
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::CurveGroup;
+use ark_ff::{batch_inversion, Field, Zero};
+
+// Curve groups in short Weierstrass form, whose affine points expose the coordinates needed for batch-affine addition
+// Every MNT curve group is a short Weierstrass projective group, so they all implement this
+pub trait SwCurveGroup: CurveGroup {
+    type SwConfig: SWCurveConfig;
+
+    // Convert points to affine form, sharing one inversion across all of them
+    fn to_sw_affine(points: &[Self]) -> Vec<Affine<Self::SwConfig>>;
+
+    // Convert an affine point back to the group
+    fn from_sw_affine(point: Affine<Self::SwConfig>) -> Self;
+}
+
+impl<P: SWCurveConfig> SwCurveGroup for Projective<P> {
+    type SwConfig = P;
+
+    fn to_sw_affine(points: &[Self]) -> Vec<Affine<P>> {
+        Self::normalize_batch(points)
+    }
+
+    fn from_sw_affine(point: Affine<P>) -> Self {
+        point.into()
+    }
+}
+
+// Add lhs[i] and rhs[i] for every i in affine form, sharing a single field inversion across all of the additions
+// An affine addition needs an inversion for its slope, Montgomery's trick replaces n inversions with one inversion and 3(n-1) multiplications
+pub fn batch_add_affine<P: SWCurveConfig>(lhs: &[Affine<P>], rhs: &[Affine<P>]) -> Vec<Affine<P>> {
+    assert_eq!(lhs.len(), rhs.len(), "Both sides of a batch addition must have the same length");
+    record(Operation::Addition, lhs.len() as u64);
+
+    // Denominator of the slope of each addition, zero when the sum does not need a slope
+    let mut inverses: Vec<P::BaseField> = lhs.iter().zip(rhs).map(|(p, q)| {
+        if p.infinity || q.infinity {
+            P::BaseField::zero()
+        } else if p.x == q.x {
+            // Doubling uses the tangent, whose slope is (3x^2 + a) / 2y
+            if p.y == q.y { p.y.double() } else { P::BaseField::zero() }
+        } else {
+            q.x - p.x
+        }
+    }).collect();
+
+    // Zeros are left in place, which is safe since the additions that produced them never read their inverse
+    batch_inversion(&mut inverses);
+
+    lhs.iter().zip(rhs).zip(inverses).map(|((p, q), inverse)| {
+        if p.infinity {
+            return *q;
+        }
+        if q.infinity {
+            return *p;
+        }
+
+        let slope = if p.x == q.x {
+            // Either q == -p, or p == q is a point of order 2, and both sums are the point at infinity
+            if p.y != q.y || p.y.is_zero() {
+                return Affine::identity();
+            }
+            let x_squared = p.x.square();
+            (x_squared.double() + x_squared + P::COEFF_A) * inverse
+        } else {
+            (q.y - p.y) * inverse
+        };
+
+        let x = slope.square() - p.x - q.x;
+        let y = slope * (p.x - x) - p.y;
+        Affine::new_unchecked(x, y)
+    }).collect()
+}
+
+// Reduce every bucket to the sum of its points
+// Each round pairs up the points of every bucket and adds all pairs with one shared inversion, halving the bucket sizes
+// After log2 of the largest bucket rounds each bucket holds a single point, empty buckets give the point at infinity
+pub fn batch_accumulate_buckets<P: SWCurveConfig>(mut buckets: Vec<Vec<Affine<P>>>) -> Vec<Affine<P>> {
+    loop {
+        let mut lhs = Vec::new();
+        let mut rhs = Vec::new();
+        for bucket in &buckets {
+            for pair in bucket.chunks_exact(2) {
+                lhs.push(pair[0]);
+                rhs.push(pair[1]);
+            }
+        }
+
+        // Every bucket holds at most one point
+        if lhs.is_empty() {
+            break;
+        }
+
+        let mut sums = batch_add_affine(&lhs, &rhs).into_iter();
+
+        // Sums come back in the order the pairs were taken, an unpaired last point is carried over to the next round
+        for bucket in buckets.iter_mut() {
+            let num_pairs = bucket.len() / 2;
+            if num_pairs == 0 {
+                continue;
+            }
+            let unpaired = if bucket.len() % 2 == 1 { bucket.last().copied() } else { None };
+            bucket.clear();
+            bucket.extend(sums.by_ref().take(num_pairs));
+            bucket.extend(unpaired);
+        }
+    }
+
+    buckets.into_iter()
+        .map(|bucket| bucket.first().copied().unwrap_or_else(Affine::identity))
+        .collect()
+}
@@ -0,0 +1,105 @@
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size_range, MsmError, MAX_FLAT_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::bucket::bucket_running_sum;
+use crate::pippenger::{partition_msm, partition_msm_field, MsmPartition};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use rayon::prelude::*;
+
+// Main function for a batch of Pippenger MSMs over the same points with different scalar vectors,
+// such as committing to several polynomials with one SRS
+pub fn batch_pippenger<G: CurveGroup, S: AsRef<[u32]> + Sync>(points: &[G], scalars_batch: &[S], window_size: usize) -> Vec<G> {
+    for scalars in scalars_batch {
+        assert_eq!(points.len(), scalars.as_ref().len(), "Points and scalars must have the same length");
+    }
+
+    let batch_partitions: Vec<Vec<MsmPartition>> = scalars_batch.par_iter()
+        .map(|scalars| partition_msm(scalars.as_ref(), window_size))
+        .collect();
+    batch_combine_partitioned_msm(&batch_partitions, points, window_size)
+}
+
+// Main function for batch_pippenger with full-width field scalars
+pub fn batch_pippenger_field<G: CurveGroup, S: AsRef<[G::ScalarField]> + Sync>(points: &[G], scalars_batch: &[S], window_size: usize) -> Vec<G> {
+    for scalars in scalars_batch {
+        assert_eq!(points.len(), scalars.as_ref().len(), "Points and scalars must have the same length");
+    }
+
+    let batch_partitions: Vec<Vec<MsmPartition>> = scalars_batch.par_iter()
+        .map(|scalars| partition_msm_field(scalars.as_ref(), window_size))
+        .collect();
+    batch_combine_partitioned_msm(&batch_partitions, points, window_size)
+}
+
+// Fallible variant of batch_pippenger that validates its inputs instead of panicking
+pub fn try_batch_pippenger<G: CurveGroup, S: AsRef<[u32]> + Sync>(points: &[G], scalars_batch: &[S], window_size: usize) -> Result<Vec<G>, MsmError> {
+    for scalars in scalars_batch {
+        check_lengths(points.len(), scalars.as_ref().len())?;
+    }
+    check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(batch_pippenger(points, scalars_batch, window_size))
+}
+
+// Fallible variant of batch_pippenger_field that validates its inputs instead of panicking
+pub fn try_batch_pippenger_field<G: CurveGroup, S: AsRef<[G::ScalarField]> + Sync>(points: &[G], scalars_batch: &[S], window_size: usize) -> Result<Vec<G>, MsmError> {
+    for scalars in scalars_batch {
+        check_lengths(points.len(), scalars.as_ref().len())?;
+    }
+    check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+    Ok(batch_pippenger_field(points, scalars_batch, window_size))
+}
+
+// Combine the partitions of every MSM in the batch, all MSMs borrow the same points
+// Each window runs as a separate task, split further across groups of batch members when there are fewer windows than threads
+pub fn batch_combine_partitioned_msm<G: CurveGroup>(batch_partitions: &[Vec<MsmPartition>], points: &[G], window_size: usize) -> Vec<G> {
+    let Some(num_partitions) = batch_partitions.first().map(Vec::len) else {
+        return Vec::new();
+    };
+
+    // Enough member groups per window to give every thread a task, every group loads each point once per window
+    let num_groups = rayon::current_num_threads().div_ceil(num_partitions.max(1)).min(batch_partitions.len());
+    let group_size = batch_partitions.len().div_ceil(num_groups);
+
+    // partition_msms[p][i] is the MSM of window p for the i-th scalar vector
+    let partition_msms: Vec<Vec<G>> = (0..num_partitions).into_par_iter()
+        .map(|partition_index| {
+            let partitions: Vec<&MsmPartition> = batch_partitions.iter().map(|partitions| &partitions[partition_index]).collect();
+            partitions.par_chunks(group_size)
+                .flat_map_iter(|group| compute_batch_msm_for_partition(group, points, window_size))
+                .collect()
+        })
+        .collect();
+
+    (0..batch_partitions.len()).map(|batch_index| {
+        // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
+        partition_msms.iter().rev().fold(G::zero(), |mut final_result, msms| {
+            let _combination_timer = StageTimer::start(Stage::WindowCombination);
+            // Double the final result window_size times to mimic scaling by 2^bit_index
+            for _ in 0..window_size {
+                final_result = double_point(final_result);
+            }
+            add_points(final_result, msms[batch_index])
+        })
+    })
+    .collect()
+}
+
+// One pass over the points fills a flat bucket array for every MSM in the group, so each point is loaded once per window
+pub fn compute_batch_msm_for_partition<G: CurveGroup>(partitions: &[&MsmPartition], points: &[G], window_size: usize) -> Vec<G> {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
+    // Bucket i of each MSM holds the points whose window value is i + 1
+    let mut batch_buckets: Vec<Vec<G>> = vec![vec![G::zero(); (1 << window_size) - 1]; partitions.len()];
+    for (index, &point) in points.iter().enumerate() {
+        for (buckets, partition) in batch_buckets.iter_mut().zip(partitions) {
+            let value = partition.window_values[index];
+            if value != 0 {
+                let bucket = value as usize - 1;
+                buckets[bucket] = add_points(buckets[bucket], point);
+            }
+        }
+    }
+    drop(fill_timer);
+
+    batch_buckets.iter().map(|buckets| bucket_running_sum(buckets)).collect()
+}
@@ -0,0 +1,96 @@
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_SID_WINDOW_SIZE};
+use crate::scalar::bigint_window;
+use crate::sid_pippenger::{sid_combine_partitioned_msm, SidMsmPartitionDecomposed};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+
+// Main function for Pippenger with Booth-encoded signed windows
+pub fn booth_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = booth_partition_msm(scalars, window_size);
+    sid_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Main function for booth_pippenger with full-width field scalars
+pub fn booth_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = booth_partition_msm_field(scalars, window_size);
+    sid_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of booth_pippenger that validates its inputs instead of panicking
+pub fn try_booth_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(booth_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of booth_pippenger_field that validates its inputs instead of panicking
+pub fn try_booth_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    Ok(booth_pippenger_field(points, scalars, window_size))
+}
+
+// Number of Booth windows for scalars of scalar_bits bits
+// The top window must start above the most significant bit so that its sign bit is zero, hence the extra window
+// whenever window_size divides scalar_bits, which plays the role of the SID overflow partition
+pub fn booth_num_windows(scalar_bits: usize, window_size: usize) -> usize {
+    scalar_bits / window_size + 1
+}
+
+// Booth digit of a (window_size + 1)-bit window whose lowest bit is the top bit of the window below
+// The digit is the window value, plus the borrowed bit, minus 2^window_size when the window's own top bit is set,
+// which lies in -2^(window_size - 1)..=2^(window_size - 1) so only 2^(window_size - 1) buckets are needed
+pub fn booth_digit(window: u64, window_size: usize) -> i64 {
+    let value = ((window >> 1) + (window & 1)) as i64;
+    let sign_bit = ((window >> window_size) & 1) as i64;
+    value - (sign_bit << window_size)
+}
+
+// One-pass signed window extraction for 32-bit scalars
+// Each digit is read from overlapping scalar bits, so no unsigned partitions or carry propagation pass are needed
+pub fn booth_partition_msm(scalars: &[u32], window_size: usize) -> Vec<SidMsmPartitionDecomposed> {
+    let _timer = StageTimer::start(Stage::Partition);
+    let num_windows = booth_num_windows(32, window_size);
+    let mask = (1u64 << (window_size + 1)) - 1;
+
+    (0..num_windows).map(|partition_index| {
+        let bit_index = partition_index * window_size;
+        let window_values = scalars.iter().map(|&scalar| {
+            // The lowest window borrows an implicit zero bit below bit 0
+            let window = if bit_index == 0 {
+                ((scalar as u64) << 1) & mask
+            } else {
+                ((scalar as u64) >> (bit_index - 1)) & mask
+            };
+            booth_digit(window, window_size)
+        }).collect();
+        SidMsmPartitionDecomposed { bit_index, window_values }
+    }).collect()
+}
+
+// One-pass signed window extraction for full-width field scalars
+pub fn booth_partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<SidMsmPartitionDecomposed> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_windows = booth_num_windows(F::MODULUS_BIT_SIZE as usize, window_size);
+
+    (0..num_windows).map(|partition_index| {
+        let bit_index = partition_index * window_size;
+        let window_values = bigints.iter().map(|scalar| {
+            let window = if bit_index == 0 {
+                (bigint_window(scalar, 0, window_size) as u64) << 1
+            } else {
+                bigint_window(scalar, bit_index - 1, window_size + 1) as u64
+            };
+            booth_digit(window, window_size)
+        }).collect();
+        SidMsmPartitionDecomposed { bit_index, window_values }
+    }).collect()
+}
@@ -0,0 +1,115 @@
+use crate::operations::{add_points, double_point, scalar_multiply};
+use crate::error::{check_lengths, check_scalar_width, MsmError};
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+use std::collections::BinaryHeap;
+
+// Bos-Coster approach to Multi-Scalar Multiplication, competitive for moderate n with large scalars
+pub fn bos_coster<G: CurveGroup>(points: &[G], scalars: &[u32]) -> G {
+    // Ensure points and scalars have the same length
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let bigints = scalars.iter()
+        .map(|&scalar| <G::ScalarField as PrimeField>::BigInt::from(scalar as u64))
+        .collect();
+    bos_coster_combine(points, bigints)
+}
+
+// Bos-Coster approach to Multi-Scalar Multiplication with full-width field scalars
+pub fn bos_coster_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField]) -> G {
+    // Ensure points and scalars have the same length
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let bigints = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    bos_coster_combine(points, bigints)
+}
+
+// Fallible variant of bos_coster that validates its inputs instead of panicking
+pub fn try_bos_coster<G: CurveGroup>(points: &[G], scalars: &[u32]) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_scalar_width::<G>(32)?;
+    Ok(bos_coster(points, scalars))
+}
+
+// Fallible variant of bos_coster_field that validates its inputs instead of panicking
+pub fn try_bos_coster_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField]) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    Ok(bos_coster_field(points, scalars))
+}
+
+// Quotients below 2^MAX_QUOTIENT_BITS are applied with a short double-and-add,
+// larger ones mean k1 dwarfs k2 and the term is finished with a scalar multiplication instead
+const MAX_QUOTIENT_BITS: u32 = 8;
+
+// Repeatedly replace the two largest terms k1*P1 + k2*P2 with (k1 mod k2)*P1 + k2*(P2 + q*P1), where q = floor(k1 / k2)
+// A max-heap keeps the largest scalar on top, so each step costs one addition plus a few for q*P1 and shrinks the largest scalar below k2
+pub fn bos_coster_combine<G: CurveGroup>(points: &[G], scalars: Vec<<G::ScalarField as PrimeField>::BigInt>) -> G {
+    let mut points = points.to_vec();
+    // Zero scalars contribute nothing, so they never enter the heap
+    let mut heap: BinaryHeap<_> = scalars.into_iter()
+        .enumerate()
+        .filter(|(_, scalar)| !scalar.is_zero())
+        .map(|(index, scalar)| (scalar, index))
+        .collect();
+
+    let mut result = G::zero();
+
+    while let Some((mut k1, i1)) = heap.pop() {
+        let Some(&(k2, i2)) = heap.peek() else {
+            // Only one term is left, so finish it with a single scalar multiplication
+            result = add_points(result, multiply_by_bigint(points[i1], k1));
+            break;
+        };
+
+        // The quotient has shift or shift + 1 bits, so a wide gap would need a long double-and-add
+        let shift = k1.num_bits() - k2.num_bits();
+        if shift >= MAX_QUOTIENT_BITS {
+            result = add_points(result, multiply_by_bigint(points[i1], k1));
+            continue;
+        }
+
+        // k1*P1 + k2*P2 = (k1 mod k2)*P1 + k2*(P2 + q*P1)
+        let quotient = divide_with_remainder(&mut k1, k2, shift);
+        points[i2] = add_points(points[i2], multiply_by_small(points[i1], quotient));
+        if !k1.is_zero() {
+            heap.push((k1, i1));
+        }
+    }
+
+    result
+}
+
+// Binary long division of dividend by divisor, whose quotient has at most shift + 1 bits
+// The remainder is left in dividend and the quotient is returned
+fn divide_with_remainder<B: BigInteger>(dividend: &mut B, divisor: B, shift: u32) -> u64 {
+    let mut shifted_divisor = divisor;
+    shifted_divisor.muln(shift);
+
+    let mut quotient = 0;
+    for bit in (0..=shift).rev() {
+        if *dividend >= shifted_divisor {
+            dividend.sub_with_borrow(&shifted_divisor);
+            quotient |= 1 << bit;
+        }
+        shifted_divisor.div2();
+    }
+    quotient
+}
+
+// Double-and-add for the small quotients, q = 1 in most steps so this is usually the point itself
+fn multiply_by_small<G: CurveGroup>(point: G, scalar: u64) -> G {
+    let mut result = point;
+    for bit in (0..63 - scalar.leading_zeros()).rev() {
+        result = double_point(result);
+        if (scalar >> bit) & 1 == 1 {
+            result = add_points(result, point);
+        }
+    }
+    result
+}
+
+// Scalars only ever shrink, so they always remain valid scalar field elements
+fn multiply_by_bigint<G: CurveGroup>(point: G, scalar: <G::ScalarField as PrimeField>::BigInt) -> G {
+    let scalar = G::ScalarField::from_bigint(scalar).expect("Bos-Coster scalars should stay below the field modulus");
+    scalar_multiply(point, scalar)
+}
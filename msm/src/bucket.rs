@@ -0,0 +1,46 @@
+use crate::operations::{add_points, negate_point};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+
+// Accumulate every point straight into a flat array of 2^window_size buckets indexed by its window value
+// Bucket 0 collects the zero window values and is never read, it only keeps the index equal to the digit
+pub fn flat_bucket_sums<G: CurveGroup>(window_values: &[u32], points: &[G], window_size: usize) -> Vec<G> {
+    let _timer = StageTimer::start(Stage::BucketFill);
+    let mut buckets = vec![G::zero(); 1 << window_size];
+    for (&value, &point) in window_values.iter().zip(points) {
+        if value != 0 {
+            buckets[value as usize] = add_points(buckets[value as usize], point);
+        }
+    }
+    buckets
+}
+
+// Accumulate every point into a flat array of 2^(window_size - 1) buckets for signed window values
+// Bucket i holds the points whose window value is ±(i + 1), with the point negated for negative values
+pub fn signed_flat_bucket_sums<G: CurveGroup>(window_values: &[i64], points: &[G], window_size: usize) -> Vec<G> {
+    let _timer = StageTimer::start(Stage::BucketFill);
+    let mut buckets = vec![G::zero(); 1 << (window_size - 1)];
+    for (&value, &point) in window_values.iter().zip(points) {
+        if value != 0 {
+            let index = value.unsigned_abs() as usize - 1;
+            let point = if value < 0 { negate_point(point) } else { point };
+            buckets[index] = add_points(buckets[index], point);
+        }
+    }
+    buckets
+}
+
+// Sum of (i + 1) * buckets[i] using the running sum, two additions per bucket
+pub fn bucket_running_sum<G: CurveGroup>(buckets: &[G]) -> G {
+    let _timer = StageTimer::start(Stage::BucketReduction);
+    let mut msm_result = G::zero();
+    let mut temp = G::zero();
+
+    // Iterating over scalar values in decreasing order
+    for &bucket in buckets.iter().rev() {
+        temp = add_points(temp, bucket);
+        msm_result = add_points(msm_result, temp);
+    }
+
+    msm_result
+}
@@ -0,0 +1,61 @@
+use crate::operations::add_points;
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::pippenger::{pippenger, pippenger_field};
+use ark_ec::CurveGroup;
+use rayon::prelude::*;
+
+// Main function for Pippenger with parallelism across chunks of points, using one chunk per worker thread
+pub fn chunked_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    chunked_pippenger_with_chunks(points, scalars, window_size, rayon::current_num_threads())
+}
+
+// Main function for chunked_pippenger with full-width field scalars
+pub fn chunked_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    chunked_pippenger_field_with_chunks(points, scalars, window_size, rayon::current_num_threads())
+}
+
+// Chunked Pippenger with an explicit number of chunks
+pub fn chunked_pippenger_with_chunks<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize, num_chunks: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let chunk_size = chunk_size(points.len(), num_chunks);
+
+    // Every chunk is a full MSM over a slice of the inputs, so the partial results only need to be summed
+    points.par_chunks(chunk_size)
+        .zip(scalars.par_chunks(chunk_size))
+        .map(|(point_chunk, scalar_chunk)| pippenger(point_chunk, scalar_chunk, window_size))
+        .reduce(G::zero, add_points)
+}
+
+// Chunked Pippenger with full-width field scalars and an explicit number of chunks
+pub fn chunked_pippenger_field_with_chunks<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize, num_chunks: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let chunk_size = chunk_size(points.len(), num_chunks);
+
+    points.par_chunks(chunk_size)
+        .zip(scalars.par_chunks(chunk_size))
+        .map(|(point_chunk, scalar_chunk)| pippenger_field(point_chunk, scalar_chunk, window_size))
+        .reduce(G::zero, add_points)
+}
+
+// Fallible variant of chunked_pippenger that validates its inputs instead of panicking
+pub fn try_chunked_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(chunked_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of chunked_pippenger_field that validates its inputs instead of panicking
+pub fn try_chunked_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(chunked_pippenger_field(points, scalars, window_size))
+}
+
+// Number of points per chunk so that num_points is split into at most num_chunks chunks
+// par_chunks panics on a chunk size of 0, so at least one point is always placed in each chunk
+pub fn chunk_size(num_points: usize, num_chunks: usize) -> usize {
+    num_points.div_ceil(num_chunks.max(1)).max(1)
+}
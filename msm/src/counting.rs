@@ -0,0 +1,160 @@
+use std::fmt;
+#[cfg(feature = "counting")]
+use crate::thread_pool::context_pool;
+#[cfg(feature = "counting")]
+use std::cell::RefCell;
+#[cfg(feature = "counting")]
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+#[cfg(feature = "counting")]
+use std::sync::Arc;
+
+// True when the crate is built with the counting feature, otherwise every OperationCounts report is zero
+pub const COUNTING_ENABLED: bool = cfg!(feature = "counting");
+
+// Group operations recorded by the functions in operations.rs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Addition,
+    Doubling,
+    Negation,
+    MixedAddition,
+    ScalarMultiplication,
+}
+
+#[cfg(feature = "counting")]
+const NUM_OPERATIONS: usize = 5;
+
+// Number of group operations of each kind in one run, independent of the machine it ran on
+// Batch-affine additions count as additions, adding the point at infinity counts like any other addition
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OperationCounts {
+    pub additions: u64,
+    pub doublings: u64,
+    pub negations: u64,
+    pub mixed_additions: u64,
+    pub scalar_multiplications: u64,
+}
+
+impl OperationCounts {
+    pub fn get(&self, operation: Operation) -> u64 {
+        match operation {
+            Operation::Addition => self.additions,
+            Operation::Doubling => self.doublings,
+            Operation::Negation => self.negations,
+            Operation::MixedAddition => self.mixed_additions,
+            Operation::ScalarMultiplication => self.scalar_multiplications,
+        }
+    }
+
+    // Sum over every kind of operation
+    pub fn total(&self) -> u64 {
+        self.additions + self.doublings + self.negations + self.mixed_additions + self.scalar_multiplications
+    }
+}
+
+impl fmt::Display for OperationCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "additions: {}, doublings: {}, negations: {}, mixed additions: {}, scalar multiplications: {}",
+            self.additions, self.doublings, self.negations, self.mixed_additions, self.scalar_multiplications)
+    }
+}
+
+// Operations recorded by one count, and the count it is nested in
+#[cfg(feature = "counting")]
+pub(crate) struct CountContext {
+    counts: [AtomicU64; NUM_OPERATIONS],
+    parent: Option<Arc<CountContext>>,
+}
+
+#[cfg(feature = "counting")]
+thread_local! {
+    // Count the operations of this thread are recorded into, the workers of the pool a count runs on share it
+    static CURRENT_COUNT: RefCell<Option<Arc<CountContext>>> = const { RefCell::new(None) };
+}
+
+// Number of counts running on any thread, so uncounted runs pay for a single load per operation
+#[cfg(feature = "counting")]
+static ACTIVE_COUNTS: AtomicUsize = AtomicUsize::new(0);
+
+// Count of the calling thread, passed on to the workers of any pool built inside it
+#[cfg(feature = "counting")]
+pub(crate) fn current_count() -> Option<Arc<CountContext>> {
+    CURRENT_COUNT.with(|current| current.borrow().clone())
+}
+
+// Make context the count of the calling thread and return the previous one
+#[cfg(feature = "counting")]
+pub(crate) fn set_current_count(context: Option<Arc<CountContext>>) -> Option<Arc<CountContext>> {
+    CURRENT_COUNT.with(|current| current.replace(context))
+}
+
+// Restores the previous count of the calling thread when a count returns or panics
+#[cfg(feature = "counting")]
+struct EnteredCount {
+    previous: Option<Arc<CountContext>>,
+}
+
+#[cfg(feature = "counting")]
+impl Drop for EnteredCount {
+    fn drop(&mut self) {
+        set_current_count(self.previous.take());
+        ACTIVE_COUNTS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Record num_operations operations of the given kind
+// Outside every count this costs one atomic load, without the counting feature it compiles to nothing
+#[inline]
+pub(crate) fn record(operation: Operation, num_operations: u64) {
+    #[cfg(not(feature = "counting"))]
+    let _ = (operation, num_operations);
+
+    #[cfg(feature = "counting")]
+    {
+        if ACTIVE_COUNTS.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        CURRENT_COUNT.with(|current| {
+            // Nested counts also record the operation in every count around them
+            let current = current.borrow();
+            let mut context = current.as_deref();
+            while let Some(count) = context {
+                count.counts[operation as usize].fetch_add(num_operations, Ordering::Relaxed);
+                context = count.parent.as_deref();
+            }
+        });
+    }
+}
+
+// Run f and count the group operations performed inside it
+// f runs on a cached rayon pool leased to this count, whose workers record into it, so operations of parallel variants inside f are included
+// and operations on any other thread, such as a concurrent MSM, are not
+// Counts may be nested, the operations of the inner count are included in the outer one as well
+pub fn count_operations<R: Send>(f: impl FnOnce() -> R + Send) -> (R, OperationCounts) {
+    #[cfg(feature = "counting")]
+    {
+        let context = Arc::new(CountContext {
+            counts: [const { AtomicU64::new(0) }; NUM_OPERATIONS],
+            parent: current_count(),
+        });
+        let result = {
+            ACTIVE_COUNTS.fetch_add(1, Ordering::SeqCst);
+            let _entered = EnteredCount { previous: set_current_count(Some(Arc::clone(&context))) };
+            let pool = context_pool(rayon::current_num_threads()).unwrap_or_else(|error| panic!("{}", error));
+            pool.install(f)
+        };
+
+        let count = |operation: Operation| context.counts[operation as usize].load(Ordering::Relaxed);
+        let counts = OperationCounts {
+            additions: count(Operation::Addition),
+            doublings: count(Operation::Doubling),
+            negations: count(Operation::Negation),
+            mixed_additions: count(Operation::MixedAddition),
+            scalar_multiplications: count(Operation::ScalarMultiplication),
+        };
+        (result, counts)
+    }
+
+    #[cfg(not(feature = "counting"))]
+    (f(), OperationCounts::default())
+}
@@ -0,0 +1,129 @@
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::SerializationError;
+use std::{fmt, io};
+
+// Smallest window for unsigned windows
+pub const MIN_WINDOW_SIZE: usize = 1;
+
+// Smallest window for Signed Integer Decomposition, with window_size = 1 the bucket range 1 << (window_size - 1) degenerates
+pub const MIN_SID_WINDOW_SIZE: usize = 2;
+
+// Largest window, window values are u32 so (1 << window_size) - 1 and 2^window_size must not overflow
+pub const MAX_WINDOW_SIZE: usize = 31;
+
+// Largest window for the flat bucket variants, which allocate all 2^window_size buckets up front
+pub const MAX_FLAT_WINDOW_SIZE: usize = 24;
+
+// Errors returned by the fallible try_* entry points
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MsmError {
+    // Points and scalars slices have different lengths
+    LengthMismatch { points: usize, scalars: usize },
+    // Window size outside the range supported by the algorithm
+    InvalidWindowSize { window_size: usize, min: usize, max: usize },
+    // Scalars are wider than the scalar field of the group, so they would be silently reduced
+    ScalarTooWide { scalar_bits: usize, max_bits: usize },
+    // The rayon pool for num_threads could not be built
+    ThreadPoolBuild { num_threads: usize, reason: String },
+    // Accumulators split scalars into different windows, so their buckets cannot be merged
+    AccumulatorMismatch { window_size: usize, num_windows: usize, other_window_size: usize, other_num_windows: usize },
+}
+
+impl fmt::Display for MsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MsmError::LengthMismatch { points, scalars } => {
+                write!(f, "Points and scalars must have the same length ({} points, {} scalars)", points, scalars)
+            }
+            MsmError::InvalidWindowSize { window_size, min, max } => {
+                write!(f, "Window size {} is outside the supported range {}..={}", window_size, min, max)
+            }
+            MsmError::ScalarTooWide { scalar_bits, max_bits } => {
+                write!(f, "Scalars of {} bits are wider than the {}-bit scalar field", scalar_bits, max_bits)
+            }
+            MsmError::ThreadPoolBuild { num_threads, reason } => {
+                write!(f, "Failed to build a thread pool with {} threads: {}", num_threads, reason)
+            }
+            MsmError::AccumulatorMismatch { window_size, num_windows, other_window_size, other_num_windows } => {
+                write!(f, "Cannot merge an accumulator with {} windows of {} bits into one with {} windows of {} bits", other_num_windows, other_window_size, num_windows, window_size)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MsmError {}
+
+// Errors returned when MSM inputs are read from or written to files
+#[derive(Debug)]
+pub enum MsmIoError {
+    // Reading or writing the underlying file failed
+    Io(io::Error),
+    // Points or scalars could not be encoded or decoded
+    Serialization(SerializationError),
+    // Text input such as JSON, hex or CSV is malformed
+    Format(String),
+    // The inputs were read but the MSM rejected them
+    Msm(MsmError),
+}
+
+impl fmt::Display for MsmIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MsmIoError::Io(error) => write!(f, "I/O error: {}", error),
+            MsmIoError::Serialization(error) => write!(f, "Serialization error: {}", error),
+            MsmIoError::Format(message) => write!(f, "Format error: {}", message),
+            MsmIoError::Msm(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for MsmIoError {}
+
+impl From<io::Error> for MsmIoError {
+    fn from(error: io::Error) -> Self {
+        MsmIoError::Io(error)
+    }
+}
+
+impl From<SerializationError> for MsmIoError {
+    fn from(error: SerializationError) -> Self {
+        MsmIoError::Serialization(error)
+    }
+}
+
+impl From<MsmError> for MsmIoError {
+    fn from(error: MsmError) -> Self {
+        MsmIoError::Msm(error)
+    }
+}
+
+// Ensure points and scalars have the same length
+pub fn check_lengths(num_points: usize, num_scalars: usize) -> Result<(), MsmError> {
+    if num_points != num_scalars {
+        return Err(MsmError::LengthMismatch { points: num_points, scalars: num_scalars });
+    }
+    Ok(())
+}
+
+// Ensure window_size lies within min_window_size..=MAX_WINDOW_SIZE
+pub fn check_window_size(window_size: usize, min_window_size: usize) -> Result<(), MsmError> {
+    check_window_size_range(window_size, min_window_size, MAX_WINDOW_SIZE)
+}
+
+// Ensure window_size lies within min_window_size..=max_window_size, for algorithms whose memory grows with 2^window_size
+pub fn check_window_size_range(window_size: usize, min_window_size: usize, max_window_size: usize) -> Result<(), MsmError> {
+    if window_size < min_window_size || window_size > max_window_size {
+        return Err(MsmError::InvalidWindowSize { window_size, min: min_window_size, max: max_window_size });
+    }
+    Ok(())
+}
+
+// Ensure scalars of scalar_bits bits fit in the scalar field of G without being reduced
+pub fn check_scalar_width<G: CurveGroup>(scalar_bits: usize) -> Result<(), MsmError> {
+    let max_bits = G::ScalarField::MODULUS_BIT_SIZE as usize;
+    if scalar_bits > max_bits {
+        return Err(MsmError::ScalarTooWide { scalar_bits, max_bits });
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use crate::operations::{add_mixed, double_point};
+use crate::error::{check_lengths, check_window_size_range, MsmError, MAX_FLAT_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::bucket::bucket_running_sum;
+use crate::scalar::{bigint_window, num_partitions};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use rayon::prelude::*;
+
+// Precomputed tables for repeated MSMs over the same bases, such as the SRS of a KZG or Groth16 prover (BGMW method)
+// Every base P is stored as 2^(k * window_size) * P for each window k, so the window of bit index k * window_size
+// can be added straight into the buckets instead of being scaled by window_size doublings when the windows are combined
+pub struct FixedBaseMsmContext<G: CurveGroup> {
+    window_size: usize,
+    scalar_bits: usize,
+    num_windows: usize,
+    num_points: usize,
+    // tables[i * num_windows + k] = 2^(k * window_size) * points[i], in affine form for mixed additions
+    tables: Vec<G::Affine>,
+}
+
+impl<G: CurveGroup> FixedBaseMsmContext<G> {
+    // Precompute tables covering full-width scalar field elements
+    pub fn new(points: &[G], window_size: usize) -> Self {
+        Self::with_scalar_bits(points, window_size, G::ScalarField::MODULUS_BIT_SIZE as usize)
+    }
+
+    // Precompute tables covering scalars of at most scalar_bits bits, e.g. 32 for u32 scalars
+    // Narrower scalars need fewer windows, so the tables take proportionally less memory
+    // Every MSM allocates all 2^window_size - 1 buckets, so windows are capped at MAX_FLAT_WINDOW_SIZE as for the flat variants
+    pub fn with_scalar_bits(points: &[G], window_size: usize, scalar_bits: usize) -> Self {
+        if let Err(error) = check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE) {
+            panic!("{}", error);
+        }
+
+        let num_windows = num_partitions(scalar_bits, window_size);
+
+        // Each table is built by doubling window_size times between consecutive windows
+        let tables: Vec<G> = points.par_iter()
+            .flat_map_iter(|&point| {
+                let mut multiple = point;
+                (0..num_windows).map(move |_| {
+                    let current = multiple;
+                    for _ in 0..window_size {
+                        multiple = double_point(multiple);
+                    }
+                    current
+                })
+            })
+            .collect();
+
+        FixedBaseMsmContext {
+            window_size,
+            scalar_bits,
+            num_windows,
+            num_points: points.len(),
+            tables: G::normalize_batch(&tables),
+        }
+    }
+
+    // Fallible variant of with_scalar_bits that rejects unsupported window sizes instead of panicking
+    pub fn try_with_scalar_bits(points: &[G], window_size: usize, scalar_bits: usize) -> Result<Self, MsmError> {
+        check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+        Ok(Self::with_scalar_bits(points, window_size, scalar_bits))
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    // Widest scalars the tables cover
+    pub fn scalar_bits(&self) -> usize {
+        self.scalar_bits
+    }
+
+    pub fn num_windows(&self) -> usize {
+        self.num_windows
+    }
+
+    pub fn num_points(&self) -> usize {
+        self.num_points
+    }
+
+    // Number of precomputed points held by the context, num_points * num_windows
+    pub fn table_size(&self) -> usize {
+        self.tables.len()
+    }
+
+    // MSM of the fixed bases with 32-bit scalars
+    pub fn msm(&self, scalars: &[u32]) -> G {
+        assert_eq!(self.num_points, scalars.len(), "Points and scalars must have the same length");
+        assert!(self.scalar_bits >= 32, "Context tables do not cover 32-bit scalars");
+
+        let mask = (1u32 << self.window_size) - 1;
+        self.accumulate(|index, window| {
+            let bit_index = window * self.window_size;
+            if bit_index >= 32 { 0 } else { (scalars[index] >> bit_index) & mask }
+        })
+    }
+
+    // MSM of the fixed bases with full-width field scalars
+    pub fn msm_field(&self, scalars: &[G::ScalarField]) -> G {
+        assert_eq!(self.num_points, scalars.len(), "Points and scalars must have the same length");
+        assert!(self.scalar_bits >= G::ScalarField::MODULUS_BIT_SIZE as usize, "Context tables do not cover full-width field scalars");
+
+        // Convert scalars out of Montgomery form once rather than once per window
+        let bigints: Vec<<G::ScalarField as PrimeField>::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+        self.accumulate(|index, window| bigint_window(&bigints[index], window * self.window_size, self.window_size))
+    }
+
+    // Fallible variant of msm that validates its inputs instead of panicking
+    pub fn try_msm(&self, scalars: &[u32]) -> Result<G, MsmError> {
+        check_lengths(self.num_points, scalars.len())?;
+        if self.scalar_bits < 32 {
+            return Err(MsmError::ScalarTooWide { scalar_bits: 32, max_bits: self.scalar_bits });
+        }
+        Ok(self.msm(scalars))
+    }
+
+    // Fallible variant of msm_field that validates its inputs instead of panicking
+    pub fn try_msm_field(&self, scalars: &[G::ScalarField]) -> Result<G, MsmError> {
+        check_lengths(self.num_points, scalars.len())?;
+        let field_bits = G::ScalarField::MODULUS_BIT_SIZE as usize;
+        if self.scalar_bits < field_bits {
+            return Err(MsmError::ScalarTooWide { scalar_bits: field_bits, max_bits: self.scalar_bits });
+        }
+        Ok(self.msm_field(scalars))
+    }
+
+    // Single bucket pass over every (base, window) pair, window_value(i, k) gives window k of scalar i
+    // All windows share the same 2^window_size - 1 buckets, so the result needs no doublings at all
+    fn accumulate(&self, window_value: impl Fn(usize, usize) -> u32) -> G {
+        let mut buckets = vec![G::zero(); (1 << self.window_size) - 1];
+
+        for index in 0..self.num_points {
+            for window in 0..self.num_windows {
+                let value = window_value(index, window);
+                if value != 0 {
+                    let bucket = value as usize - 1;
+                    buckets[bucket] = add_mixed(buckets[bucket], self.tables[index * self.num_windows + window]);
+                }
+            }
+        }
+
+        bucket_running_sum(&buckets)
+    }
+}
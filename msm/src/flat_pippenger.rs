@@ -0,0 +1,65 @@
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size_range, MsmError, MAX_FLAT_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::bucket::{bucket_running_sum, flat_bucket_sums};
+use crate::pippenger::{partition_msm, partition_msm_field, MsmPartition};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+
+// Main function for Pippenger with flat bucket arrays
+pub fn flat_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = partition_msm(scalars, window_size);
+    flat_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Main function for flat_pippenger with full-width field scalars
+pub fn flat_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = partition_msm_field(scalars, window_size);
+    flat_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of flat_pippenger that validates its inputs instead of panicking
+pub fn try_flat_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(flat_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of flat_pippenger_field that validates its inputs instead of panicking
+pub fn try_flat_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+    Ok(flat_pippenger_field(points, scalars, window_size))
+}
+
+pub fn flat_compute_msm_for_partition<G: CurveGroup>(partition: &MsmPartition, points: &[G], window_size: usize) -> G {
+    // Points are added to their bucket as they are read, so no index lists are allocated
+    let buckets = flat_bucket_sums(&partition.window_values, points, window_size);
+
+    // Skip bucket 0, bucket i then sits at index i - 1 as the running sum expects
+    bucket_running_sum(&buckets[1..])
+}
+
+pub fn flat_combine_partitioned_msm<G: CurveGroup>(partitions: &[MsmPartition], points: &[G], window_size: usize) -> G {
+    let mut final_result = G::zero();
+
+    // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
+    for partition in partitions.iter().rev() {
+        let partition_msm = flat_compute_msm_for_partition(partition, points, window_size);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
+
+        // Double the final result window_size times to mimic scaling by 2^bit_index
+        for _ in 0..window_size {
+            final_result = double_point(final_result);
+        }
+
+        // Adding the partition MSM to the final result
+        final_result = add_points(final_result, partition_msm);
+    }
+
+    final_result
+}
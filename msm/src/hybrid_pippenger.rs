@@ -0,0 +1,94 @@
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::chunked_pippenger::chunk_size;
+use crate::parallel_pippenger::{parallel_partition_msm, parallel_partition_msm_field, parallel_compute_msm_for_partition, ParallelMsmPartition};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use rayon::prelude::*;
+
+// Main function for Pippenger with parallelism across both chunks of points and windows, using one chunk per worker thread
+pub fn hybrid_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    hybrid_pippenger_with_chunks(points, scalars, window_size, rayon::current_num_threads())
+}
+
+// Main function for hybrid_pippenger with full-width field scalars
+pub fn hybrid_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    hybrid_pippenger_field_with_chunks(points, scalars, window_size, rayon::current_num_threads())
+}
+
+// Hybrid Pippenger with an explicit number of chunks
+pub fn hybrid_pippenger_with_chunks<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize, num_chunks: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let chunk_size = chunk_size(points.len(), num_chunks);
+    let chunks: Vec<HybridMsmChunk<G>> = points.chunks(chunk_size)
+        .zip(scalars.chunks(chunk_size))
+        .map(|(points, scalar_chunk)| HybridMsmChunk { points, partitions: parallel_partition_msm(scalar_chunk, window_size) })
+        .collect();
+
+    hybrid_combine_chunked_msm(&chunks, window_size)
+}
+
+// Hybrid Pippenger with full-width field scalars and an explicit number of chunks
+pub fn hybrid_pippenger_field_with_chunks<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize, num_chunks: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let chunk_size = chunk_size(points.len(), num_chunks);
+    let chunks: Vec<HybridMsmChunk<G>> = points.chunks(chunk_size)
+        .zip(scalars.chunks(chunk_size))
+        .map(|(points, scalar_chunk)| HybridMsmChunk { points, partitions: parallel_partition_msm_field(scalar_chunk, window_size) })
+        .collect();
+
+    hybrid_combine_chunked_msm(&chunks, window_size)
+}
+
+// Fallible variant of hybrid_pippenger that validates its inputs instead of panicking
+pub fn try_hybrid_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(hybrid_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of hybrid_pippenger_field that validates its inputs instead of panicking
+pub fn try_hybrid_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(hybrid_pippenger_field(points, scalars, window_size))
+}
+
+// A slice of the points together with the window partitions of its scalars
+pub struct HybridMsmChunk<'a, G: CurveGroup> {
+    pub points: &'a [G],
+    pub partitions: Vec<ParallelMsmPartition>,
+}
+
+pub fn hybrid_combine_chunked_msm<G: CurveGroup>(chunks: &[HybridMsmChunk<G>], window_size: usize) -> G {
+    // Every chunk has the same number of partitions since they all share the scalar width
+    let num_partitions = chunks.first().map_or(0, |chunk| chunk.partitions.len());
+
+    // One task per (partition, chunk) pair, so there are num_partitions * num_chunks tasks to spread over the pool
+    // Summing over the chunks gives the bucket sum of each partition for the whole input
+    let partition_results: Vec<G> = (0..num_partitions).into_par_iter()
+        .map(|partition_index| {
+            chunks.par_iter()
+                .map(|chunk| parallel_compute_msm_for_partition(&chunk.partitions[partition_index], chunk.points, window_size))
+                .reduce(G::zero, add_points)
+        })
+        .collect();
+    let _combination_timer = StageTimer::start(Stage::WindowCombination);
+
+    // Combine results, iterating through partitions in reverse to ensure doubling mimics the bit scaling process accurately
+    let mut final_result = G::zero();
+    for partition_result in partition_results.into_iter().rev() {
+        // Double the final result window_size times to mimic scaling by 2^bit_index
+        for _ in 0..window_size {
+            final_result = double_point(final_result);
+        }
+
+        // Adding the partition MSM to the final result
+        final_result = add_points(final_result, partition_result);
+    }
+
+    final_result
+}
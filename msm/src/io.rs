@@ -0,0 +1,153 @@
+use crate::error::MsmIoError;
+use ark_ec::AffineRepr;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use serde_json::{json, Value};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+// Inputs of a single MSM, so jobs can be exchanged with other tools
+pub struct MsmJob<A: AffineRepr> {
+    pub points: Vec<A>,
+    pub scalars: Vec<A::ScalarField>,
+}
+
+// Write any arkworks value in CanonicalSerialize binary form, slices and vectors are prefixed with their length as a u64
+pub fn write_binary<T: CanonicalSerialize + ?Sized, W: Write>(value: &T, writer: W, compress: Compress) -> Result<(), MsmIoError> {
+    let mut writer = BufWriter::new(writer);
+    value.serialize_with_mode(&mut writer, compress)?;
+    writer.flush()?;
+    Ok(())
+}
+
+// Read any arkworks value written by write_binary with the same compression
+pub fn read_binary<T: CanonicalDeserialize, R: Read>(reader: R, compress: Compress, validate: Validate) -> Result<T, MsmIoError> {
+    Ok(T::deserialize_with_mode(BufReader::new(reader), compress, validate)?)
+}
+
+pub fn write_binary_file<T: CanonicalSerialize + ?Sized>(value: &T, path: impl AsRef<Path>, compress: Compress) -> Result<(), MsmIoError> {
+    write_binary(value, File::create(path)?, compress)
+}
+
+pub fn read_binary_file<T: CanonicalDeserialize>(path: impl AsRef<Path>, compress: Compress, validate: Validate) -> Result<T, MsmIoError> {
+    read_binary(File::open(path)?, compress, validate)
+}
+
+// Binary job layout: the points followed by the scalars, each in the CanonicalSerialize layout of a slice
+pub fn write_job_binary<A: AffineRepr, W: Write>(job: &MsmJob<A>, writer: W, compress: Compress) -> Result<(), MsmIoError> {
+    let mut writer = BufWriter::new(writer);
+    job.points.serialize_with_mode(&mut writer, compress)?;
+    job.scalars.serialize_with_mode(&mut writer, compress)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn read_job_binary<A: AffineRepr, R: Read>(reader: R, compress: Compress, validate: Validate) -> Result<MsmJob<A>, MsmIoError> {
+    let mut reader = BufReader::new(reader);
+    let points = Vec::<A>::deserialize_with_mode(&mut reader, compress, validate)?;
+    let scalars = Vec::<A::ScalarField>::deserialize_with_mode(&mut reader, compress, validate)?;
+    Ok(MsmJob { points, scalars })
+}
+
+// Hex string of the compressed CanonicalSerialize bytes of a value
+pub fn to_hex<T: CanonicalSerialize>(value: &T) -> Result<String, MsmIoError> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut bytes)?;
+    Ok(hex::encode(bytes))
+}
+
+// Value from the hex string of its compressed CanonicalSerialize bytes, an optional 0x prefix is accepted
+pub fn from_hex<T: CanonicalDeserialize>(hex_string: &str) -> Result<T, MsmIoError> {
+    let digits = hex_string.strip_prefix("0x").unwrap_or(hex_string);
+    let bytes = hex::decode(digits).map_err(|error| MsmIoError::Format(format!("Invalid hex string {:?}: {}", hex_string, error)))?;
+    Ok(T::deserialize_compressed(&bytes[..])?)
+}
+
+// JSON job layout: {"points": [hex, ...], "scalars": [hex, ...]} with every value hex encoded in compressed form
+pub fn write_job_json<A: AffineRepr, W: Write>(job: &MsmJob<A>, writer: W) -> Result<(), MsmIoError> {
+    let points = job.points.iter().map(to_hex).collect::<Result<Vec<_>, _>>()?;
+    let scalars = job.scalars.iter().map(to_hex).collect::<Result<Vec<_>, _>>()?;
+    write_json(&json!({ "points": points, "scalars": scalars }), writer)
+}
+
+pub fn read_job_json<A: AffineRepr, R: Read>(reader: R) -> Result<MsmJob<A>, MsmIoError> {
+    let value = read_json(reader)?;
+    Ok(MsmJob {
+        points: hex_array(&value, "points")?,
+        scalars: hex_array(&value, "scalars")?,
+    })
+}
+
+// JSON result layout: {"result": hex} with the result hex encoded in compressed form
+pub fn write_result_json<T: CanonicalSerialize, W: Write>(result: &T, writer: W) -> Result<(), MsmIoError> {
+    write_json(&json!({ "result": to_hex(result)? }), writer)
+}
+
+pub fn read_result_json<T: CanonicalDeserialize, R: Read>(reader: R) -> Result<T, MsmIoError> {
+    let value = read_json(reader)?;
+    let result = value.get("result")
+        .and_then(Value::as_str)
+        .ok_or_else(|| MsmIoError::Format("Expected a \"result\" hex string".to_string()))?;
+    from_hex(result)
+}
+
+pub(crate) fn write_json<W: Write>(value: &Value, writer: W) -> Result<(), MsmIoError> {
+    let mut writer = BufWriter::new(writer);
+    serde_json::to_writer_pretty(&mut writer, value).map_err(|error| MsmIoError::Format(error.to_string()))?;
+    writeln!(writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub(crate) fn read_json<R: Read>(reader: R) -> Result<Value, MsmIoError> {
+    serde_json::from_reader(BufReader::new(reader)).map_err(|error| MsmIoError::Format(error.to_string()))
+}
+
+// Decode the array of hex strings stored under key
+fn hex_array<T: CanonicalDeserialize>(value: &Value, key: &str) -> Result<Vec<T>, MsmIoError> {
+    let array = value.get(key)
+        .and_then(Value::as_array)
+        .ok_or_else(|| MsmIoError::Format(format!("Expected a {:?} array", key)))?;
+
+    array.iter()
+        .map(|entry| {
+            let hex_string = entry.as_str().ok_or_else(|| MsmIoError::Format(format!("Expected hex strings in {:?}", key)))?;
+            from_hex(hex_string)
+        })
+        .collect()
+}
+
+// CSV of decimal scalars, one per line, e.g. u32 scalars or scalar field elements
+pub fn write_scalars_csv<T: Display, W: Write>(scalars: &[T], writer: W) -> Result<(), MsmIoError> {
+    let mut writer = BufWriter::new(writer);
+    for scalar in scalars {
+        writeln!(writer, "{}", scalar)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// Read decimal scalars separated by commas or newlines, blank entries are skipped
+pub fn read_scalars_csv<T: FromStr, R: Read>(reader: R) -> Result<Vec<T>, MsmIoError> {
+    let mut scalars = Vec::new();
+
+    for (line_index, line) in BufReader::new(reader).lines().enumerate() {
+        for entry in line?.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let scalar = entry.parse()
+                .map_err(|_| MsmIoError::Format(format!("Invalid decimal scalar {:?} on line {}", entry, line_index + 1)))?;
+            scalars.push(scalar);
+        }
+    }
+
+    Ok(scalars)
+}
+
+pub fn write_scalars_csv_file<T: Display>(scalars: &[T], path: impl AsRef<Path>) -> Result<(), MsmIoError> {
+    write_scalars_csv(scalars, File::create(path)?)
+}
+
+pub fn read_scalars_csv_file<T: FromStr>(path: impl AsRef<Path>) -> Result<Vec<T>, MsmIoError> {
+    read_scalars_csv(File::open(path)?)
+}
@@ -1,16 +1,44 @@
 extern crate ark_ff;
-extern crate ark_mnt4_298;
 extern crate ark_std;
 extern crate ark_ec;
 
+pub mod algorithm;
+pub mod error;
 pub mod operations;
+pub mod scalar;
+pub mod thread_pool;
+pub mod profiling;
+pub mod counting;
+pub mod tuning;
+pub mod window;
 pub mod pippenger;
 pub mod naive;
 pub mod trivial;
+pub mod bos_coster;
 pub mod parallel_pippenger;
 pub mod subsum_pippenger;
 pub mod sid_pippenger;
+pub mod booth;
+pub mod signed_digits;
 pub mod parallel_sid_pippenger;
 pub mod parallel_subsum_pippenger;
 pub mod sid_subsum_pippenger;
-pub mod parallel_sid_subsum_pippenger; 
\ No newline at end of file
+pub mod parallel_sid_subsum_pippenger;
+pub mod chunked_pippenger;
+pub mod hybrid_pippenger;
+pub mod batch_pippenger;
+pub mod accumulator;
+pub mod out_of_core;
+pub mod io;
+pub mod batch_affine;
+pub mod batch_affine_pippenger;
+pub mod sid_batch_affine_pippenger;
+pub mod affine_pippenger;
+pub mod sid_affine_pippenger;
+pub mod bucket;
+pub mod flat_pippenger;
+pub mod flat_sid_pippenger;
+pub mod fixed_base;
+pub mod straus;
+pub mod wnaf;
+pub mod wnaf_msm;
\ No newline at end of file
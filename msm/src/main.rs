@@ -1,112 +1,385 @@
-extern crate ark_ff;
-extern crate ark_ec;
-extern crate ark_mnt4_298;
-extern crate ark_std;
-extern crate std;
-
-mod operations;
-mod pippenger;
-mod parallel_pippenger;
-mod naive;
-mod sid_pippenger;
-mod subsum_pippenger;
-mod parallel_sid_pippenger;
-mod parallel_subsum_pippenger;
-mod sid_subsum_pippenger;
-mod parallel_sid_subsum_pippenger;
-mod trivial;
-
-use ark_mnt4_298::G1Projective;
-use ark_std::{UniformRand, test_rng};
-use rand::{Rng, thread_rng};
-use std::time::Instant;
-use parallel_pippenger::parallel_pippenger;
-use pippenger::pippenger;
-use naive::naive_msm;
-use trivial::trivial_msm;
-use sid_pippenger::sid_pippenger;
-use subsum_pippenger::subsum_pippenger;
-use parallel_sid_pippenger::parallel_sid_pippenger;
-use parallel_subsum_pippenger::parallel_subsum_pippenger;
-use sid_subsum_pippenger::sid_subsum_pippenger;
-use parallel_sid_subsum_pippenger::parallel_sid_subsum_pippenger;
-
-fn main() {
-
-    fn generate_points(num_points: usize) -> Vec<G1Projective> {
-        let mut rng = test_rng();
-        (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_serialize::{Compress, Validate};
+use clap::{Parser, Subcommand, ValueEnum};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::error::Error;
+use std::fs::File;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use msm::algorithm::{algorithm_names, algorithms, get_algorithm, MsmAlgorithm, MsmConfig};
+use msm::batch_affine::SwCurveGroup;
+use msm::counting::count_operations;
+use msm::error::MsmError;
+use msm::io::{read_binary, read_job_json, read_scalars_csv, to_hex, write_result_json};
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::profiling::{profile, PROFILING_ENABLED};
+use msm::tuning::{install_profile_file, save_profile_file, tune, TuningConfig};
+
+#[derive(Parser)]
+#[command(name = "msm", about = "Multi-Scalar Multiplication on MNT curves")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compute a single MSM and print its result
+    Run(Args),
+    /// Time algorithms on the same inputs
+    Bench {
+        #[command(flatten)]
+        args: Args,
+        /// Number of timed runs per algorithm, the median is reported
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+    },
+    /// Check algorithms against naive MSM
+    Verify(Args),
+    /// Find the fastest algorithm and window for each input size and save them to a tuning profile
+    Tune(TuneArgs),
+}
+
+impl Command {
+    fn curve(&self) -> Curve {
+        match self {
+            Command::Run(args) | Command::Verify(args) => args.curve,
+            Command::Bench { args, .. } => args.curve,
+            Command::Tune(args) => args.curve,
+        }
     }
-    
-    fn generate_scalars(num_points: usize) -> Vec<u32> {
-        let mut rng = thread_rng();
-        (0..num_points).map(|_| rng.gen_range(0..4294967295)).collect() // 32-bit integers
+}
+
+#[derive(clap::Args)]
+struct Args {
+    /// Algorithm name from the registry, or "all" [default: auto for run, all for bench and verify]
+    #[arg(long)]
+    algorithm: Option<String>,
+    /// Number of randomly generated point-scalar pairs
+    #[arg(long, default_value_t = 100)]
+    points: usize,
+    /// Window size, chosen per algorithm from the input size when omitted
+    #[arg(long)]
+    window: Option<usize>,
+    /// Bit width of generated scalars, up to 32 uses u32 scalars and wider uses scalar field elements
+    #[arg(long, default_value_t = 32)]
+    scalar_bits: usize,
+    /// Curve whose G1 group the MSM runs on
+    #[arg(long, value_enum, default_value_t = Curve::Mnt4_298)]
+    curve: Curve,
+    /// Seed for generated inputs, a random seed is chosen and printed when omitted
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Threads for the parallel algorithms, rayon's global pool is used when omitted
+    #[arg(long)]
+    threads: Option<usize>,
+    /// Read points from a CanonicalSerialize binary file instead of generating them
+    #[arg(long)]
+    points_file: Option<PathBuf>,
+    /// Points in points_file are compressed
+    #[arg(long)]
+    compressed: bool,
+    /// Read decimal scalars from a CSV file instead of generating them
+    #[arg(long)]
+    scalars_file: Option<PathBuf>,
+    /// Read points and scalars from a hex-encoded JSON job file
+    #[arg(long, conflicts_with_all = ["points_file", "scalars_file"])]
+    job_file: Option<PathBuf>,
+    /// Write the result of run as hex-encoded JSON, requires a single algorithm
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Count the group operations of run, which slows the timed run slightly
+    #[arg(long)]
+    count_operations: bool,
+    /// Tuning profile written by the tune subcommand, used by the auto algorithm for the chosen curve
+    #[arg(long, env = "MSM_TUNING_PROFILE")]
+    tuning_profile: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct TuneArgs {
+    /// Curve whose G1 group is tuned
+    #[arg(long, value_enum, default_value_t = Curve::Mnt4_298)]
+    curve: Curve,
+    /// Input sizes to measure, as comma-separated powers of two
+    #[arg(long, value_delimiter = ',', default_values_t = [4, 6, 8, 10, 12, 14, 16])]
+    log_sizes: Vec<u32>,
+    /// Largest window size to try
+    #[arg(long, default_value_t = 16)]
+    max_window: usize,
+    /// Number of timed runs per configuration, the median is compared
+    #[arg(long, default_value_t = 3)]
+    runs: usize,
+    /// Only tune u32 scalars, skipping scalar field elements
+    #[arg(long)]
+    u32_only: bool,
+    /// Profile file to write, profiles of other curves already in it are kept
+    #[arg(long, default_value = "msm-tuning.json")]
+    profile: PathBuf,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Curve {
+    #[value(name = "mnt4-298")]
+    Mnt4_298,
+    #[value(name = "mnt4-753")]
+    Mnt4_753,
+    #[value(name = "mnt6-298")]
+    Mnt6_298,
+    #[value(name = "mnt6-753")]
+    Mnt6_753,
+}
+
+impl Curve {
+    // Name the curve's G1 group is stored under in tuning profiles, the same as its command line value
+    fn group_name(self) -> String {
+        self.to_possible_value().expect("Every curve has a value").get_name().to_string()
     }
+}
 
-    let num_points = 100;
+// Scalars are kept as u32 when they fit, so the u32 entry points of each algorithm are used
+enum Scalars<F> {
+    Small(Vec<u32>),
+    Field(Vec<F>),
+}
 
-    let points = generate_points(num_points);
-    let scalars = generate_scalars(num_points);
-    let window_size = 2;
+struct Inputs<G: CurveGroup> {
+    points: Vec<G>,
+    scalars: Scalars<G::ScalarField>,
+}
 
-    let start = Instant::now();
-    let result_naive = naive_msm(&points, &scalars);
-    let duration_naive = start.elapsed();
-        
-    let start = Instant::now();
-    let result_trivial = trivial_msm(&points, &scalars);
-    let duration_trivial = start.elapsed();
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    let start = Instant::now();
-    let result_pippenger = pippenger(&points, &scalars, window_size);
-    let duration_pippenger = start.elapsed();
+    let outcome = match cli.command.curve() {
+        Curve::Mnt4_298 => execute::<ark_mnt4_298::G1Projective>(&cli.command),
+        Curve::Mnt4_753 => execute::<ark_mnt4_753::G1Projective>(&cli.command),
+        Curve::Mnt6_298 => execute::<ark_mnt6_298::G1Projective>(&cli.command),
+        Curve::Mnt6_753 => execute::<ark_mnt6_753::G1Projective>(&cli.command),
+    };
 
-    let start = Instant::now();
-    let result_parallel = parallel_pippenger(&points, &scalars, window_size);
-    let duration_parallel = start.elapsed();
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    let start = Instant::now();
-    let result_sid = sid_pippenger(&points, &scalars, window_size);
-    let duration_sid = start.elapsed();
+// Run the subcommand on the curve group G, returning false when verify finds a mismatch
+fn execute<G: SwCurveGroup>(command: &Command) -> Result<bool, Box<dyn Error>> {
+    let args = match command {
+        Command::Run(args) | Command::Verify(args) => args,
+        Command::Bench { args, .. } => args,
+        Command::Tune(args) => return tune_curve::<G>(args),
+    };
+    if let Some(tuning_profile) = &args.tuning_profile {
+        install_profile_file::<G>(tuning_profile, &args.curve.group_name())?;
+    }
+    let inputs = load_inputs::<G>(args)?;
+    let config = MsmConfig { window_size: args.window, num_threads: args.threads };
 
-    let start = Instant::now();
-    let result_subsum = subsum_pippenger(&points, &scalars, window_size);
-    let duration_subsum = start.elapsed();
+    match command {
+        Command::Run(args) => {
+            let name = args.algorithm.as_deref().unwrap_or("auto");
+            let selected = select_algorithms::<G>(name)?;
+            // Every algorithm would overwrite the same file, so only a single result can be written
+            if args.output.is_some() && selected.len() > 1 {
+                return Err("--output writes a single result, choose one algorithm instead of \"all\"".into());
+            }
+            for algorithm in &selected {
+                if skip_window(algorithm.as_ref(), name, &config) {
+                    continue;
+                }
+                let timed_run = || profile(|| time(|| compute(algorithm.as_ref(), &inputs, &config)));
+                let ((outcome, timings), counts) = if args.count_operations {
+                    let (timed_outcome, counts) = count_operations(timed_run);
+                    (timed_outcome, Some(counts))
+                } else {
+                    (timed_run(), None)
+                };
+                let (result, duration) = outcome?;
+                println!("{}: {:?}", algorithm.name(), duration);
+                if PROFILING_ENABLED {
+                    println!("stages: {}", timings);
+                }
+                if let Some(counts) = counts {
+                    println!("operations: {}", counts);
+                }
+                println!("result: {}", to_hex(&result.into_affine())?);
 
-    let start = Instant::now();
-    let result_parallel_sid = parallel_sid_pippenger(&points, &scalars, window_size);
-    let duration_parallel_sid = start.elapsed();
+                if let Some(output) = &args.output {
+                    write_result_json(&result.into_affine(), File::create(output)?)?;
+                }
+            }
+            Ok(true)
+        }
+        Command::Bench { args, runs } => {
+            let name = args.algorithm.as_deref().unwrap_or("all");
+            for algorithm in select_algorithms::<G>(name)? {
+                if skip_window(algorithm.as_ref(), name, &config) {
+                    continue;
+                }
+                let mut durations = (0..(*runs).max(1))
+                    .map(|_| time(|| compute(algorithm.as_ref(), &inputs, &config)).map(|(_, duration)| duration))
+                    .collect::<Result<Vec<Duration>, _>>()?;
+                durations.sort();
+                println!("{}: {:?}", algorithm.name(), durations[durations.len() / 2]);
+            }
+            Ok(true)
+        }
+        Command::Verify(args) => {
+            // Reference result to check every algorithm against
+            let expected_result = match &inputs.scalars {
+                Scalars::Small(scalars) => naive_msm(&inputs.points, scalars),
+                Scalars::Field(scalars) => naive_msm_field(&inputs.points, scalars),
+            };
 
-    let start = Instant::now();
-    let result_parallel_subsum = parallel_subsum_pippenger(&points, &scalars, window_size);
-    let duration_parallel_subsum = start.elapsed();
+            let mut all_match = true;
+            let name = args.algorithm.as_deref().unwrap_or("all");
+            for algorithm in select_algorithms::<G>(name)? {
+                if skip_window(algorithm.as_ref(), name, &config) {
+                    continue;
+                }
+                let matches = compute(algorithm.as_ref(), &inputs, &config)? == expected_result;
+                println!("{}: {}", algorithm.name(), if matches { "ok" } else { "MISMATCH" });
+                all_match &= matches;
+            }
+            Ok(all_match)
+        }
+        Command::Tune(_) => unreachable!("tune returns before the inputs are loaded"),
+    }
+}
 
-    let start = Instant::now();
-    let result_sid_subsum = sid_subsum_pippenger(&points, &scalars, window_size);
-    let duration_sid_subsum = start.elapsed();
+// Tune every algorithm on the curve group G and save the winners to the profile file
+fn tune_curve<G: SwCurveGroup>(args: &TuneArgs) -> Result<bool, Box<dyn Error>> {
+    let config = TuningConfig {
+        sizes: args.log_sizes.iter().map(|&log_size| 1 << log_size).collect(),
+        windows: 1..=args.max_window,
+        runs: args.runs,
+        field_scalars: !args.u32_only,
+        ..TuningConfig::default()
+    };
+    let profile = tune::<G>(&args.curve.group_name(), &config);
+
+    for entry in &profile.entries {
+        println!("{} points, u32 scalars: {}", entry.num_points, entry);
+    }
+    for entry in &profile.field_entries {
+        println!("{} points, field scalars: {}", entry.num_points, entry);
+    }
 
+    save_profile_file(&profile, &args.profile)?;
+    println!("profile: {}", args.profile.display());
+    Ok(true)
+}
+
+// "all" selects every algorithm in the registry, any other name selects a single algorithm
+fn select_algorithms<G: SwCurveGroup>(name: &str) -> Result<Vec<Box<dyn MsmAlgorithm<G>>>, String> {
+    if name == "all" {
+        return Ok(algorithms::<G>());
+    }
+
+    get_algorithm::<G>(name)
+        .map(|algorithm| vec![algorithm])
+        .ok_or_else(|| format!("Unknown algorithm {:?}, expected \"all\" or one of: {}", name, algorithm_names::<G>().join(", ")))
+}
+
+// "all" gives every algorithm the same --window, so algorithms whose window range excludes it are skipped instead of failing the command
+// A single algorithm named on the command line still reports the invalid window as an error
+fn skip_window<G: SwCurveGroup>(algorithm: &dyn MsmAlgorithm<G>, name: &str, config: &MsmConfig) -> bool {
+    let out_of_range = match (config.window_size, algorithm.window_range()) {
+        (Some(window_size), Some(window_range)) => !window_range.contains(&window_size),
+        _ => false,
+    };
+    if name == "all" && out_of_range {
+        println!("{}: skipped (window out of range)", algorithm.name());
+        return true;
+    }
+    false
+}
+
+fn compute<G: SwCurveGroup>(algorithm: &dyn MsmAlgorithm<G>, inputs: &Inputs<G>, config: &MsmConfig) -> Result<G, MsmError> {
+    match &inputs.scalars {
+        Scalars::Small(scalars) => algorithm.try_compute(&inputs.points, scalars, config),
+        Scalars::Field(scalars) => algorithm.try_compute_field(&inputs.points, scalars, config),
+    }
+}
+
+fn time<R, E>(f: impl FnOnce() -> Result<R, E>) -> Result<(R, Duration), E> {
     let start = Instant::now();
-    let result_parallel_sid_subsum = parallel_sid_subsum_pippenger(&points, &scalars, window_size);
-    let duration_parallel_sid_subsum = start.elapsed();
-
-    assert_eq!(result_naive, result_pippenger, "Results of pippenger and naive MSM should match");
-    assert_eq!(result_naive, result_trivial, "Results of trivial and naive MSM should match");
-    assert_eq!(result_naive, result_parallel, "Results of pippenger with parallelism and naive MSM should match");
-    assert_eq!(result_naive, result_sid, "Results of pippenger with Signed Integer Decomposition should match with naive MSM");
-    assert_eq!(result_naive, result_subsum, "Results of pippenger with more efficient subsum accumulation should match with naive MSM");
-    assert_eq!(result_naive, result_parallel_sid, "Results of pippenger with Signed Integer Decomposition and parallelism should match with naive MSM");
-    assert_eq!(result_naive, result_parallel_subsum, "Results of pippenger with more efficient subsum accumulation and parallelism should match with naive MSM");
-    assert_eq!(result_naive, result_sid_subsum, "Results of pippenger with Signed Integer Decomposition and more efficient subsum accumulation should match with naive MSM");
-    assert_eq!(result_naive, result_parallel_sid_subsum, "Results of pippenger with Signed Integer Decomposition, parallelism and more efficient subsum accumulation should match with naive MSM");
-
-    println!("Naive: {:?}", duration_naive);
-    println!("Trivial: {:?}", duration_trivial);
-    println!("Pippenger: {:?}", duration_pippenger);
-    println!("Parallel: {:?}", duration_parallel);
-    println!("SID: {:?}", duration_sid);
-    println!("Subsum: {:?}", duration_subsum);
-    println!("Parallel SID: {:?}", duration_parallel_sid);
-    println!("Parallel Subsum: {:?}", duration_parallel_subsum);
-    println!("SID Subsum: {:?}", duration_sid_subsum);
-    println!("Parallel SID Subsum: {:?}", duration_parallel_sid_subsum);    
-}
\ No newline at end of file
+    let result = f()?;
+    Ok((result, start.elapsed()))
+}
+
+// Read inputs from the files given, generating whatever is missing from the seed
+fn load_inputs<G: SwCurveGroup>(args: &Args) -> Result<Inputs<G>, Box<dyn Error>> {
+    if let Some(job_file) = &args.job_file {
+        let job = read_job_json::<G::Affine, _>(File::open(job_file)?)?;
+        let points = job.points.iter().map(|point| point.into_group()).collect();
+        return Ok(Inputs { points, scalars: Scalars::Field(job.scalars) });
+    }
+
+    let seed = args.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    if args.seed.is_none() && (args.points_file.is_none() || args.scalars_file.is_none()) {
+        println!("seed: {}", seed);
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let points: Vec<G> = match &args.points_file {
+        Some(points_file) => {
+            let compress = if args.compressed { Compress::Yes } else { Compress::No };
+            let affine_points: Vec<G::Affine> = read_binary(File::open(points_file)?, compress, Validate::Yes)?;
+            affine_points.iter().map(|point| point.into_group()).collect()
+        }
+        None => (0..args.points).map(|_| G::rand(&mut rng)).collect(),
+    };
+
+    let scalars = match &args.scalars_file {
+        // Decimal scalars that all fit in a u32 are kept as u32, anything wider is read as field elements
+        Some(scalars_file) => match read_scalars_csv::<u32, _>(File::open(scalars_file)?) {
+            Ok(scalars) => Scalars::Small(scalars),
+            Err(_) => Scalars::Field(read_scalars_csv(File::open(scalars_file)?)?),
+        },
+        None => generate_scalars::<G::ScalarField>(points.len(), args.scalar_bits, &mut rng),
+    };
+
+    let num_scalars = match &scalars {
+        Scalars::Small(scalars) => scalars.len(),
+        Scalars::Field(scalars) => scalars.len(),
+    };
+    if num_scalars != points.len() {
+        return Err(format!("Read {} points but {} scalars", points.len(), num_scalars).into());
+    }
+
+    Ok(Inputs { points, scalars })
+}
+
+// Random scalars of at most scalar_bits bits
+fn generate_scalars<F: PrimeField>(num_scalars: usize, scalar_bits: usize, rng: &mut StdRng) -> Scalars<F> {
+    if scalar_bits <= 32 {
+        let mask = if scalar_bits == 32 { u32::MAX } else { (1u32 << scalar_bits) - 1 };
+        return Scalars::Small((0..num_scalars).map(|_| rng.gen::<u32>() & mask).collect());
+    }
+
+    // Scalars at least as wide as the field modulus are uniform field elements
+    if scalar_bits >= F::MODULUS_BIT_SIZE as usize {
+        return Scalars::Field((0..num_scalars).map(|_| F::rand(rng)).collect());
+    }
+
+    Scalars::Field((0..num_scalars).map(|_| {
+        let mut bytes = vec![0u8; scalar_bits.div_ceil(8)];
+        rng.fill(&mut bytes[..]);
+        // Clear the bits above scalar_bits in the most significant byte
+        let top_bits = scalar_bits % 8;
+        if top_bits != 0 {
+            bytes[scalar_bits / 8] &= (1u8 << top_bits) - 1;
+        }
+        F::from_le_bytes_mod_order(&bytes)
+    }).collect())
+}
@@ -1,18 +1,46 @@
 use crate::operations::{add_points, scalar_multiply};
-use ark_mnt4_298::G1Projective;
-use ark_ff::Zero;
+use crate::error::{check_lengths, check_scalar_width, MsmError};
+use ark_ec::CurveGroup;
 
 // Naive approach to Multi-Scalar Multiplication
-pub fn naive_msm(points: &[G1Projective], scalars: &[u32]) -> G1Projective {
+pub fn naive_msm<G: CurveGroup>(points: &[G], scalars: &[u32]) -> G {
     // Ensure points and scalars have the same length
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
 
-    let mut result = G1Projective::zero();
+    let mut result = G::zero();
     
     for (&scalar, point) in scalars.iter().zip(points) {
-        result = add_points(result, scalar_multiply(*point, scalar.into()));
+        result = add_points(result, scalar_multiply(*point, G::ScalarField::from(scalar)));
     }
 
     // Return a single point as MSM result
     result
 }
+
+// Naive approach to Multi-Scalar Multiplication with full-width field scalars
+pub fn naive_msm_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField]) -> G {
+    // Ensure points and scalars have the same length
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let mut result = G::zero();
+
+    for (&scalar, point) in scalars.iter().zip(points) {
+        result = add_points(result, scalar_multiply(*point, scalar));
+    }
+
+    // Return a single point as MSM result
+    result
+}
+
+// Fallible variant of naive_msm that validates its inputs instead of panicking
+pub fn try_naive_msm<G: CurveGroup>(points: &[G], scalars: &[u32]) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_scalar_width::<G>(32)?;
+    Ok(naive_msm(points, scalars))
+}
+
+// Fallible variant of naive_msm_field that validates its inputs instead of panicking
+pub fn try_naive_msm_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField]) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    Ok(naive_msm_field(points, scalars))
+}
@@ -1,15 +1,38 @@
 extern crate ark_ec;
 extern crate ark_ff;
-extern crate ark_mnt4_298;
 
-use ark_mnt4_298::{G1Projective, Fr};
+use crate::counting::{record, Operation};
+use ark_ec::CurveGroup;
+use std::ops::Neg;
+
+// Every operation is recorded for count_operations in counting.rs, so the algorithms perform their group operations through these functions
 
 // Point Addition - Adds two points on an MNT curve.
-pub fn add_points (point1: G1Projective, point2: G1Projective) -> G1Projective {
+pub fn add_points<G: CurveGroup>(point1: G, point2: G) -> G {
+    record(Operation::Addition, 1);
     point1 + point2
 }
 
+// Mixed Addition - Adds an affine point to a projective point on an MNT curve, cheaper than add_points since the affine point has Z = 1.
+pub fn add_mixed<G: CurveGroup>(point: G, affine_point: G::Affine) -> G {
+    record(Operation::MixedAddition, 1);
+    point + affine_point
+}
+
+// Point Doubling - Adds a point on an MNT curve to itself.
+pub fn double_point<G: CurveGroup>(point: G) -> G {
+    record(Operation::Doubling, 1);
+    point.double()
+}
+
+// Point Negation - Negates a projective or affine point on an MNT curve.
+pub fn negate_point<P: Neg<Output = P>>(point: P) -> P {
+    record(Operation::Negation, 1);
+    -point
+}
+
 // Scalar Multiplication - Multiplies a point on an MNT curve by a scalar.
-pub fn scalar_multiply (point: G1Projective, scalar: Fr) -> G1Projective  {
+pub fn scalar_multiply<G: CurveGroup>(point: G, scalar: G::ScalarField) -> G {
+    record(Operation::ScalarMultiplication, 1);
     point * scalar
-}
\ No newline at end of file
+}
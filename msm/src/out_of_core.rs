@@ -0,0 +1,132 @@
+use crate::operations::add_points;
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmIoError, MIN_WINDOW_SIZE};
+use crate::affine_pippenger::{affine_pippenger, affine_pippenger_field};
+use crate::io::write_binary;
+use crate::scalar::num_partitions;
+use crate::window::{optimal_window_size, PippengerVariant};
+use ark_ec::AffineRepr;
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+
+// Settings for MSMs over points too large to hold in memory, such as an MNT4-753 SRS of 2^26 points
+pub struct OutOfCoreConfig {
+    // Window size for every chunk, None chooses the optimal window for the chunk size
+    pub window_size: Option<usize>,
+    // Upper bound in bytes on the memory used for the points of a chunk, their partitions and the buckets of a partition
+    pub memory_budget: usize,
+    // Whether the points file stores compressed points
+    pub compress: Compress,
+    // Whether each point read is checked to lie on the curve and in the prime order subgroup
+    pub validate: Validate,
+}
+
+impl Default for OutOfCoreConfig {
+    fn default() -> Self {
+        OutOfCoreConfig {
+            window_size: None,
+            memory_budget: 1 << 30,
+            compress: Compress::No,
+            validate: Validate::Yes,
+        }
+    }
+}
+
+// Write points in the layout read by the out-of-core MSM: the number of points as a u64 followed by each point,
+// which is the binary layout of a slice written by io::write_binary
+pub fn write_points<A: AffineRepr, W: Write>(points: &[A], writer: W, compress: Compress) -> Result<(), MsmIoError> {
+    write_binary(points, writer, compress)
+}
+
+// Write points to a file at path
+pub fn write_points_file<A: AffineRepr>(points: &[A], path: impl AsRef<Path>, compress: Compress) -> Result<(), MsmIoError> {
+    write_points(points, File::create(path)?, compress)
+}
+
+// Number of points that fit in memory_budget bytes, counting the affine point and one u32 window value per partition
+// plus the bucket index each point takes up while a partition is processed
+// A partition also keeps one bucket entry per distinct window value, at most min(points, 2^window_size - 1) of them
+pub fn out_of_core_chunk_size<A: AffineRepr>(memory_budget: usize, scalar_bits: usize, window_size: usize) -> usize {
+    let bytes_per_point = std::mem::size_of::<A>()
+        + num_partitions(scalar_bits, window_size) * std::mem::size_of::<u32>()
+        + std::mem::size_of::<usize>();
+    let bytes_per_bucket = std::mem::size_of::<u32>() + std::mem::size_of::<Vec<usize>>();
+    let max_buckets = (1usize << window_size) - 1;
+
+    // While every point may open its own bucket both are charged per point, beyond that the buckets are a fixed cost
+    let chunk_size = memory_budget / (bytes_per_point + bytes_per_bucket);
+    let chunk_size = if chunk_size <= max_buckets {
+        chunk_size
+    } else {
+        (memory_budget - max_buckets * bytes_per_bucket) / bytes_per_point
+    };
+    chunk_size.max(1)
+}
+
+// Main function for MSM over points read in chunks from reader with 32-bit scalars held in memory
+pub fn out_of_core_msm<A: AffineRepr, R: Read>(reader: R, scalars: &[u32], config: &OutOfCoreConfig) -> Result<A::Group, MsmIoError> {
+    check_scalar_width::<A::Group>(32)?;
+    out_of_core_combine(reader, scalars.len(), 32, config, |points: &[A], offset, window_size| {
+        affine_pippenger(points, &scalars[offset..offset + points.len()], window_size)
+    })
+}
+
+// Main function for out_of_core_msm with full-width field scalars
+pub fn out_of_core_msm_field<A: AffineRepr, R: Read>(reader: R, scalars: &[A::ScalarField], config: &OutOfCoreConfig) -> Result<A::Group, MsmIoError> {
+    out_of_core_combine(reader, scalars.len(), A::ScalarField::MODULUS_BIT_SIZE as usize, config, |points: &[A], offset, window_size| {
+        affine_pippenger_field(points, &scalars[offset..offset + points.len()], window_size)
+    })
+}
+
+// out_of_core_msm over the points file at path, read through a buffer
+pub fn out_of_core_msm_file<A: AffineRepr>(path: impl AsRef<Path>, scalars: &[u32], config: &OutOfCoreConfig) -> Result<A::Group, MsmIoError> {
+    out_of_core_msm::<A, _>(BufReader::new(File::open(path)?), scalars, config)
+}
+
+// out_of_core_msm_field over the points file at path, read through a buffer
+pub fn out_of_core_msm_field_file<A: AffineRepr>(path: impl AsRef<Path>, scalars: &[A::ScalarField], config: &OutOfCoreConfig) -> Result<A::Group, MsmIoError> {
+    out_of_core_msm_field::<A, _>(BufReader::new(File::open(path)?), scalars, config)
+}
+
+// Read the points chunk by chunk, run chunk_msm(points, offset, window_size) on each and sum the partial results
+// Only one chunk of points is held at a time, the buffer is reused between chunks
+fn out_of_core_combine<A: AffineRepr, R: Read>(
+    mut reader: R,
+    num_scalars: usize,
+    scalar_bits: usize,
+    config: &OutOfCoreConfig,
+    chunk_msm: impl Fn(&[A], usize, usize) -> A::Group,
+) -> Result<A::Group, MsmIoError> {
+    let num_points = u64::deserialize_with_mode(&mut reader, config.compress, Validate::No)? as usize;
+    check_lengths(num_points, num_scalars)?;
+
+    // The window depends on the chunk size and the chunk size on the window, so size the chunk by its points alone first
+    let window_size = match config.window_size {
+        Some(window_size) => window_size,
+        None => {
+            let max_chunk_points = (config.memory_budget / std::mem::size_of::<A>()).clamp(1, num_points.max(1));
+            optimal_window_size(max_chunk_points, scalar_bits, PippengerVariant::Standard)
+        }
+    };
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    let chunk_size = out_of_core_chunk_size::<A>(config.memory_budget, scalar_bits, window_size);
+
+    let mut result = A::Group::zero();
+    let mut chunk: Vec<A> = Vec::with_capacity(chunk_size.min(num_points));
+    let mut offset = 0;
+
+    while offset < num_points {
+        let chunk_len = chunk_size.min(num_points - offset);
+        chunk.clear();
+        for _ in 0..chunk_len {
+            chunk.push(A::deserialize_with_mode(&mut reader, config.compress, config.validate)?);
+        }
+
+        result = add_points(result, chunk_msm(&chunk, offset, window_size));
+        offset += chunk_len;
+    }
+
+    Ok(result)
+}
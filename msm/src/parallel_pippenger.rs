@@ -1,18 +1,43 @@
-use crate::operations::add_points;
-use ark_ec::Group;
-use ark_ff::Zero;
-use ark_mnt4_298::G1Projective;
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use std::collections::HashMap;
-use std::thread;
+use rayon::prelude::*;
 
 // Main function for Pippenger with parallelism
-pub fn parallel_pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -> G1Projective {
+pub fn parallel_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
     
     let partitions = parallel_partition_msm(scalars, window_size);
     parallel_combine_partitioned_msm(&partitions, points, window_size)
 }
 
+// Main function for parallel_pippenger with full-width field scalars
+pub fn parallel_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = parallel_partition_msm_field(scalars, window_size);
+    parallel_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of parallel_pippenger that validates its inputs instead of panicking
+pub fn try_parallel_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(parallel_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of parallel_pippenger_field that validates its inputs instead of panicking
+pub fn try_parallel_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(parallel_pippenger_field(points, scalars, window_size))
+}
+
 pub struct ParallelMsmPartition {
     pub bit_index: usize,
     pub window_values: Vec<u32>,
@@ -29,7 +54,8 @@ impl Clone for ParallelMsmPartition {
 }
 
 pub fn parallel_partition_msm(scalars: &[u32], window_size: usize) -> Vec<ParallelMsmPartition> {
-    let num_partitions = (32 + window_size - 1) / window_size;
+    let _timer = StageTimer::start(Stage::Partition);
+    let num_partitions = 32usize.div_ceil(window_size);
     let mut partitions = Vec::new();
 
     for partition_index in 0..num_partitions {
@@ -43,17 +69,39 @@ pub fn parallel_partition_msm(scalars: &[u32], window_size: usize) -> Vec<Parall
     partitions
 }
 
-pub fn parallel_compute_msm_for_partition(partition: &ParallelMsmPartition, points: &[G1Projective], window_size: usize) -> G1Projective {
+// Step 1 for field scalars: Split MSM with b-bit scalars into b/c MSMs, where b is the bit size of the field modulus
+pub fn parallel_partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<ParallelMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_partitions = num_partitions(F::MODULUS_BIT_SIZE as usize, window_size);
+    let mut partitions = Vec::new();
+
+    for partition_index in 0..num_partitions {
+        let bit_index = partition_index * window_size;
+        let window_values: Vec<u32> = bigints.iter().map(|scalar| {
+            bigint_window(scalar, bit_index, window_size)
+        }).collect();
+        partitions.push(ParallelMsmPartition { bit_index, window_values });
+    }
+
+    partitions
+}
+
+pub fn parallel_compute_msm_for_partition<G: CurveGroup>(partition: &ParallelMsmPartition, points: &[G], window_size: usize) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
     let mut buckets: HashMap<u32, Vec<usize>> = HashMap::new();
     for (index, &value) in partition.window_values.iter().enumerate() {
         if value != 0 {
-        buckets.entry(value).or_insert_with(Vec::new).push(index);
+        buckets.entry(value).or_default().push(index);
         }
     }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
 
     // Variables to store the computed MSM for this partition
-    let mut msm_result = G1Projective::zero();
-    let mut temp = G1Projective::zero();
+    let mut msm_result = G::zero();
+    let mut temp = G::zero();
 
     // Get the maximum scalar value (which is the number of buckets minus 1)
     let max_scalar_value = (1 << window_size) - 1;
@@ -62,9 +110,9 @@ pub fn parallel_compute_msm_for_partition(partition: &ParallelMsmPartition, poin
     for scalar_value in (1..=max_scalar_value).rev() {
         if let Some(indexes) = buckets.get(&scalar_value) {
             // Summing up the points corresponding to the indexes in the bucket
-            let sum_of_points: G1Projective = indexes.iter()
+            let sum_of_points: G = indexes.iter()
                 .map(|&i| points[i])
-                .fold(G1Projective::zero(), |acc, p| add_points(acc, p));
+                .fold(G::zero(), add_points);
 
             // Add to temp regardless of whether sum_of_points is zero
             temp = add_points(temp, sum_of_points);
@@ -78,30 +126,19 @@ pub fn parallel_compute_msm_for_partition(partition: &ParallelMsmPartition, poin
 }
 
 
-pub fn parallel_combine_partitioned_msm(partitions: &[ParallelMsmPartition], points: &[G1Projective], window_size: usize) -> G1Projective {
-    let mut handles = Vec::new();
-
-    // Spawn a thread for each partition, iterate through them in reverse to ensure doubling mimics the bit scaling process accurately
-    for partition in partitions.iter().rev() {
-        let partition_clone = partition.clone();
-        let points_clone = points.to_vec();
-
-        let handle = thread::spawn(move || {
-            let msm_result = parallel_compute_msm_for_partition(&partition_clone, &points_clone, window_size);
-            msm_result
-        });
-
-        handles.push(handle);
-    }
+pub fn parallel_combine_partitioned_msm<G: CurveGroup>(partitions: &[ParallelMsmPartition], points: &[G], window_size: usize) -> G {
+    // Compute the MSM for each partition on the rayon pool, every task borrows the same points instead of cloning them
+    let partition_results: Vec<G> = partitions.par_iter()
+        .map(|partition| parallel_compute_msm_for_partition(partition, points, window_size))
+        .collect();
+    let _combination_timer = StageTimer::start(Stage::WindowCombination);
 
-    // Collect results from each thread and combine
-    let mut final_result = G1Projective::zero();
-    for handle in handles {
-        let partition_result = handle.join().unwrap();
-        
+    // Combine results, iterating through partitions in reverse to ensure doubling mimics the bit scaling process accurately
+    let mut final_result = G::zero();
+    for partition_result in partition_results.into_iter().rev() {
         // Double the final result window_size times to mimic scaling by 2^bit_index
         for _ in 0..window_size {
-            final_result = final_result.double();
+            final_result = double_point(final_result);
         }
 
         // Adding the partition MSM to the final result
@@ -1,13 +1,14 @@
-use crate::operations::add_points;
-use ark_ec::Group;
-use ark_ff::Zero;
-use ark_mnt4_298::G1Projective;
+use crate::operations::{add_points, double_point, negate_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_SID_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use std::collections::BTreeMap;
-use std::ops::Neg;
-use std::thread;
+use rayon::prelude::*;
 
 // Main function for Pippenger with parallelism and Signed Integer Decomposition
-pub fn parallel_sid_subsum_pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -> G1Projective {
+pub fn parallel_sid_subsum_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
     
     let partitions = parallel_sid_subsum_partition_msm(scalars, window_size);
@@ -15,6 +16,30 @@ pub fn parallel_sid_subsum_pippenger(points: &[G1Projective], scalars: &[u32], w
     parallel_sid_subsum_combine_partitioned_msm(&decomposed_partitions, points, window_size)
 }
 
+// Main function for parallel_sid_subsum_pippenger with full-width field scalars
+pub fn parallel_sid_subsum_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = parallel_sid_subsum_partition_msm_field(scalars, window_size);
+    let decomposed_partitions = parallel_sid_subsum_decompose_partitions(&partitions, window_size);
+    parallel_sid_subsum_combine_partitioned_msm(&decomposed_partitions, points, window_size)
+}
+
+// Fallible variant of parallel_sid_subsum_pippenger that validates its inputs instead of panicking
+pub fn try_parallel_sid_subsum_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(parallel_sid_subsum_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of parallel_sid_subsum_pippenger_field that validates its inputs instead of panicking
+pub fn try_parallel_sid_subsum_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    Ok(parallel_sid_subsum_pippenger_field(points, scalars, window_size))
+}
+
 pub struct ParallelSidSubsumMsmPartition {
     pub bit_index: usize,
     pub window_values: Vec<u32>,
@@ -47,7 +72,8 @@ impl Clone for ParallelSidSubsumMsmPartitionDecomposed {
 }
 
 pub fn parallel_sid_subsum_partition_msm(scalars: &[u32], window_size: usize) -> Vec<ParallelSidSubsumMsmPartition> {
-    let num_partitions = (32 + window_size - 1) / window_size;
+    let _timer = StageTimer::start(Stage::Partition);
+    let num_partitions = 32usize.div_ceil(window_size);
     let mut partitions = Vec::new();
 
     for partition_index in 0..num_partitions {
@@ -61,7 +87,27 @@ pub fn parallel_sid_subsum_partition_msm(scalars: &[u32], window_size: usize) ->
     partitions
 }
 
+// Step 1 for field scalars: Split MSM with b-bit scalars into b/c MSMs, where b is the bit size of the field modulus
+pub fn parallel_sid_subsum_partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<ParallelSidSubsumMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_partitions = num_partitions(F::MODULUS_BIT_SIZE as usize, window_size);
+    let mut partitions = Vec::new();
+
+    for partition_index in 0..num_partitions {
+        let bit_index = partition_index * window_size;
+        let window_values: Vec<u32> = bigints.iter().map(|scalar| {
+            bigint_window(scalar, bit_index, window_size)
+        }).collect();
+        partitions.push(ParallelSidSubsumMsmPartition { bit_index, window_values });
+    }
+
+    partitions
+}
+
 pub fn parallel_sid_subsum_decompose_partitions(partitions: &[ParallelSidSubsumMsmPartition], window_size: usize) -> Vec<ParallelSidSubsumMsmPartitionDecomposed> {
+    let _timer = StageTimer::start(Stage::Decompose);
     let base = 2u32.pow(window_size as u32);
     let threshold = base / 2;
 
@@ -73,15 +119,20 @@ pub fn parallel_sid_subsum_decompose_partitions(partitions: &[ParallelSidSubsumM
         })
         .collect();
 
-    // Append an extra partition for overflow handling
-    let last_bit_index = partitions.last().unwrap().bit_index + window_size;
-    decomposed_partitions.push(ParallelSidSubsumMsmPartitionDecomposed {
-        bit_index: last_bit_index,
-        window_values: vec![0i64; partitions[0].window_values.len()], // Initialise with zeros for overflow handling
-    });
+    // Without partitions there are no window values to decompose
+    let Some(last_partition) = partitions.last() else {
+        return decomposed_partitions;
+    };
+    let num_scalars = last_partition.window_values.len();
+
+    // Extra partition for overflow handling
+    let mut overflow_partition = ParallelSidSubsumMsmPartitionDecomposed {
+        bit_index: last_partition.bit_index + window_size,
+        window_values: vec![0i64; num_scalars], // Initialise with zeros for overflow handling
+    };
 
-    // Iterate through each position of window values across all partitions, not window_values in the same bit_index
-    for i in 0..partitions[0].window_values.len() {
+    // Iterate through each position of window values across all partitions
+    for i in 0..num_scalars {
         let mut carry = 0i64;
 
         for j in 0..partitions.len() {
@@ -98,15 +149,19 @@ pub fn parallel_sid_subsum_decompose_partitions(partitions: &[ParallelSidSubsumM
             }
         }
 
-        if carry > 0 {
-            decomposed_partitions[partitions.len()].window_values[i] += carry;
-        }
+        overflow_partition.window_values[i] = carry;
+    }
+
+    // A top window narrower than window_size - 1 bits never carries, so the overflow partition is only kept when a carry reaches it
+    if overflow_partition.window_values.iter().any(|&value| value != 0) {
+        decomposed_partitions.push(overflow_partition);
     }
 
     decomposed_partitions
 }
 
-pub fn parallel_sid_subsum_compute_msm_for_partition(partition: &ParallelSidSubsumMsmPartitionDecomposed, points: &[G1Projective]) -> G1Projective {
+pub fn parallel_sid_subsum_compute_msm_for_partition<G: CurveGroup>(partition: &ParallelSidSubsumMsmPartitionDecomposed, points: &[G]) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
     let mut buckets: BTreeMap<u32, Vec<(usize, i64)>> = BTreeMap::new();
 
     // Add an empty bucket with index 0 as requirement for new parallel_subsum accumulation algorithm
@@ -115,10 +170,12 @@ pub fn parallel_sid_subsum_compute_msm_for_partition(partition: &ParallelSidSubs
     // Assign points to buckets based on the absolute value while keeping track of the original value's sign
     for (index, &value) in partition.window_values.iter().enumerate() {
         if value != 0 {
-            let abs_value = value.abs() as u32;
-            buckets.entry(abs_value).or_insert_with(Vec::new).push((index, value));
+            let abs_value = value.unsigned_abs() as u32;
+            buckets.entry(abs_value).or_default().push((index, value));
         }
     }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
 
     // Collect all the scalar values (keys of the buckets) into a vector
     let scalars: Vec<u32> = buckets.keys().copied().collect();
@@ -130,22 +187,22 @@ pub fn parallel_sid_subsum_compute_msm_for_partition(partition: &ParallelSidSubs
         .unwrap_or(1) as usize;
     
     // Initialise tmp array of length max_diff + 1
-    let mut tmp = vec![G1Projective::zero(); max_diff + 1];
+    let mut tmp = vec![G::zero(); max_diff + 1];
 
     // Use a peekable iterator to keep track of the next_scalar logic
     let mut iter = buckets.iter().rev().peekable();
 
     // Iterate through the sorted buckets in reverse order
     while let Some((&scalar, indexes)) = iter.next() {
-        let sum_of_points: G1Projective = indexes.iter()
+        let sum_of_points: G = indexes.iter()
                 .map(|&(i, sign)| {
                     let mut point = points[i];
                     if sign < 0 {
-                        point = point.neg(); // Negate the point if the original value was negative
+                        point = negate_point(point); // Negate the point if the original value was negative
                     }
                     point
                 })
-                .fold(G1Projective::zero(), |acc, p| add_points(acc, p));
+                .fold(G::zero(), add_points);
 
         tmp[0] = add_points(tmp[0], sum_of_points);
         
@@ -159,8 +216,8 @@ pub fn parallel_sid_subsum_compute_msm_for_partition(partition: &ParallelSidSubs
         }
     }
 
-    let mut temp = G1Projective::zero();
-    let mut msm_result = G1Projective::zero();
+    let mut temp = G::zero();
+    let mut msm_result = G::zero();
 
     // Subsum accumulation on tmp array
     for i in (1..=max_diff).rev() {
@@ -171,30 +228,19 @@ pub fn parallel_sid_subsum_compute_msm_for_partition(partition: &ParallelSidSubs
     msm_result
 }
 
-pub fn parallel_sid_subsum_combine_partitioned_msm(partitions: &[ParallelSidSubsumMsmPartitionDecomposed], points: &[G1Projective], window_size: usize) -> G1Projective {
-    let mut handles = Vec::new();
-
-    // Spawn a thread for each partition, iterate through them in reverse to ensure doubling mimics the bit scaling process accurately
-    for partition in partitions.iter().rev() {
-        let partition_clone = partition.clone();
-        let points_clone = points.to_vec();
-
-        let handle = thread::spawn(move || {
-            let msm_result = parallel_sid_subsum_compute_msm_for_partition(&partition_clone, &points_clone);
-            msm_result
-        });
-
-        handles.push(handle);
-    }
+pub fn parallel_sid_subsum_combine_partitioned_msm<G: CurveGroup>(partitions: &[ParallelSidSubsumMsmPartitionDecomposed], points: &[G], window_size: usize) -> G {
+    // Compute the MSM for each partition on the rayon pool, every task borrows the same points instead of cloning them
+    let partition_results: Vec<G> = partitions.par_iter()
+        .map(|partition| parallel_sid_subsum_compute_msm_for_partition(partition, points))
+        .collect();
+    let _combination_timer = StageTimer::start(Stage::WindowCombination);
 
-    // Collect results from each thread and combine
-    let mut final_result = G1Projective::zero();
-    for handle in handles {
-        let partition_result = handle.join().unwrap();
-        
+    // Combine results, iterating through partitions in reverse to ensure doubling mimics the bit scaling process accurately
+    let mut final_result = G::zero();
+    for partition_result in partition_results.into_iter().rev() {
         // Double the final result window_size times to mimic scaling by 2^bit_index
         for _ in 0..window_size {
-            final_result = final_result.double();
+            final_result = double_point(final_result);
         }
 
         // Adding the partition MSM to the final result
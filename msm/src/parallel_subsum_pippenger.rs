@@ -1,12 +1,14 @@
-use crate::operations::add_points;
-use ark_ec::Group;
-use ark_ff::Zero;
-use ark_mnt4_298::G1Projective;
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use std::collections::BTreeMap;
-use std::thread;
+use rayon::prelude::*;
 
 // Main pippenger function
-pub fn parallel_subsum_pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -> G1Projective {
+pub fn parallel_subsum_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
 
     // Ensure points and scalars have the same length
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
@@ -15,6 +17,29 @@ pub fn parallel_subsum_pippenger(points: &[G1Projective], scalars: &[u32], windo
     parallel_subsum_combine_partitioned_msm(&partitions, points, window_size)
 }
 
+// Main function for parallel_subsum_pippenger with full-width field scalars
+pub fn parallel_subsum_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = parallel_subsum_partition_msm_field(scalars, window_size);
+    parallel_subsum_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of parallel_subsum_pippenger that validates its inputs instead of panicking
+pub fn try_parallel_subsum_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(parallel_subsum_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of parallel_subsum_pippenger_field that validates its inputs instead of panicking
+pub fn try_parallel_subsum_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(parallel_subsum_pippenger_field(points, scalars, window_size))
+}
+
 pub struct ParallelSubsumMsmPartition {
     pub bit_index: usize,
     pub window_values: Vec<u32>,
@@ -32,11 +57,12 @@ impl Clone for ParallelSubsumMsmPartition {
 
 // Step 1: Split MSM with 32-bit scalars into 32/c MSMs with c-bit scalars. c == window_size
 pub fn parallel_subsum_partition_msm(scalars: &[u32], window_size: usize) -> Vec<ParallelSubsumMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
     
     // Calculate the total number of partitions based on window size
-    // (32 + window_size - 1) / window_size is used so that if 32 divides window_size, num_partitions will return the divisor
-    // But if 32 does not divide window_size, then num_partitions will round up the float instead of round down, the default in Rust
-    let num_partitions = (32 + window_size - 1) / window_size;
+    // div_ceil is used so that if window_size divides 32, num_partitions will return the quotient
+    // But if window_size does not divide 32, then num_partitions will round up instead of round down, the default in Rust
+    let num_partitions = 32usize.div_ceil(window_size);
 
     // Vector to hold information on partitions
     let mut partitions = Vec::new();
@@ -58,8 +84,28 @@ pub fn parallel_subsum_partition_msm(scalars: &[u32], window_size: usize) -> Vec
     partitions
 }
 
+// Step 1 for field scalars: Split MSM with b-bit scalars into b/c MSMs, where b is the bit size of the field modulus
+pub fn parallel_subsum_partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<ParallelSubsumMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_partitions = num_partitions(F::MODULUS_BIT_SIZE as usize, window_size);
+    let mut partitions = Vec::new();
+
+    for partition_index in 0..num_partitions {
+        let bit_index = partition_index * window_size;
+        let window_values: Vec<u32> = bigints.iter().map(|scalar| {
+            bigint_window(scalar, bit_index, window_size)
+        }).collect();
+        partitions.push(ParallelSubsumMsmPartition { bit_index, window_values });
+    }
+
+    partitions
+}
+
 // Step 2: Compute MSM for each partition using parallel_subsum accumulation
-pub fn parallel_subsum_compute_msm_for_partition(partition: &ParallelSubsumMsmPartition, points: &[G1Projective]) -> G1Projective {
+pub fn parallel_subsum_compute_msm_for_partition<G: CurveGroup>(partition: &ParallelSubsumMsmPartition, points: &[G]) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
     let mut buckets: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
 
     // Add an empty bucket with index 0 as requirement for new parallel_subsum accumulation algorithm
@@ -68,9 +114,11 @@ pub fn parallel_subsum_compute_msm_for_partition(partition: &ParallelSubsumMsmPa
     // Populate buckets with indexes grouped by their scalar value
     for (index, &value) in partition.window_values.iter().enumerate() {
         if value != 0 {
-            buckets.entry(value).or_insert_with(Vec::new).push(index);
+            buckets.entry(value).or_default().push(index);
         }
     }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
 
     // Collect all the scalar values (keys of the buckets) into a vector
     let scalars: Vec<u32> = buckets.keys().copied().collect();
@@ -82,7 +130,7 @@ pub fn parallel_subsum_compute_msm_for_partition(partition: &ParallelSubsumMsmPa
         .unwrap_or(1) as usize;
     
     // Initialise tmp array of length max_diff + 1
-    let mut tmp = vec![G1Projective::zero(); max_diff + 1];
+    let mut tmp = vec![G::zero(); max_diff + 1];
 
     // Use a peekable iterator to keep track of the next_scalar logic
     let mut iter = buckets.iter().rev().peekable();
@@ -91,7 +139,7 @@ pub fn parallel_subsum_compute_msm_for_partition(partition: &ParallelSubsumMsmPa
     while let Some((&scalar, indexes)) = iter.next() {
         let sum_of_points = indexes.iter()
             .map(|&index| points[index])
-            .fold(G1Projective::zero(), |acc, point| add_points(acc, point));
+            .fold(G::zero(), add_points);
 
         tmp[0] = add_points(tmp[0], sum_of_points);
 
@@ -105,8 +153,8 @@ pub fn parallel_subsum_compute_msm_for_partition(partition: &ParallelSubsumMsmPa
         }
     }
 
-    let mut temp = G1Projective::zero();
-    let mut msm_result = G1Projective::zero();
+    let mut temp = G::zero();
+    let mut msm_result = G::zero();
 
     // ParallelSubsum accumulation on tmp array
     for i in (1..=max_diff).rev() {
@@ -118,30 +166,19 @@ pub fn parallel_subsum_compute_msm_for_partition(partition: &ParallelSubsumMsmPa
 }
 
 // Step 3: Compute the final MSM result by combining all partitions
-pub fn parallel_subsum_combine_partitioned_msm(partitions: &[ParallelSubsumMsmPartition], points: &[G1Projective], window_size: usize) -> G1Projective {
-    let mut handles = Vec::new();
-
-    // Spawn a thread for each partition, iterate through them in reverse to ensure doubling mimics the bit scaling process accurately
-    for partition in partitions.iter().rev() {
-        let partition_clone = partition.clone();
-        let points_clone = points.to_vec();
-
-        let handle = thread::spawn(move || {
-            let msm_result = parallel_subsum_compute_msm_for_partition(&partition_clone, &points_clone);
-            msm_result
-        });
-
-        handles.push(handle);
-    }
-
-    // Collect results from each thread and combine
-    let mut final_result = G1Projective::zero();
-    for handle in handles {
-        let partition_result = handle.join().unwrap();
-        
+pub fn parallel_subsum_combine_partitioned_msm<G: CurveGroup>(partitions: &[ParallelSubsumMsmPartition], points: &[G], window_size: usize) -> G {
+    // Compute the MSM for each partition on the rayon pool, every task borrows the same points instead of cloning them
+    let partition_results: Vec<G> = partitions.par_iter()
+        .map(|partition| parallel_subsum_compute_msm_for_partition(partition, points))
+        .collect();
+    let _combination_timer = StageTimer::start(Stage::WindowCombination);
+
+    // Combine results, iterating through partitions in reverse to ensure doubling mimics the bit scaling process accurately
+    let mut final_result = G::zero();
+    for partition_result in partition_results.into_iter().rev() {
         // Double the final result window_size times to mimic scaling by 2^bit_index
         for _ in 0..window_size {
-            final_result = final_result.double();
+            final_result = double_point(final_result);
         }
 
         // Adding the partition MSM to the final result
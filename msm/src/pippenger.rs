@@ -1,12 +1,13 @@
-use crate::operations::add_points;
-use ark_ec::Group;
-use ark_ff::Zero;
-use ark_mnt4_298::G1Projective;
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use std::collections::HashMap;
-// use std::time::Instant;
 
 // Main pippenger function
-pub fn pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -> G1Projective {
+pub fn pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
 
     // Ensure points and scalars have the same length
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
@@ -15,6 +16,29 @@ pub fn pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -
     combine_partitioned_msm(&partitions, points, window_size)
 }
 
+// Main function for pippenger with full-width field scalars
+pub fn pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = partition_msm_field(scalars, window_size);
+    combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of pippenger that validates its inputs instead of panicking
+pub fn try_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of pippenger_field that validates its inputs instead of panicking
+pub fn try_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(pippenger_field(points, scalars, window_size))
+}
+
 pub struct MsmPartition {
     pub bit_index: usize,
     pub window_values: Vec<u32>,
@@ -22,12 +46,12 @@ pub struct MsmPartition {
 
 /// Step 1: Split MSM with b-bit scalars into b/c MSMs with c-bit scalars. c == window_size
 pub fn partition_msm(scalars: &[u32], window_size: usize) -> Vec<MsmPartition> {
-    // let start_partitioning = Instant::now();
+    let _timer = StageTimer::start(Stage::Partition);
     
     // Calculate the total number of partitions based on window size
-    // (32 + window_size - 1) / window_size is used so that if 32 divides window_size, num_partitions will return the divisor
-    // But if 32 does not divide window_size, then num_partitions will round up the float instead of round down, the default in Rust
-    let num_partitions = (32 + window_size - 1) / window_size;
+    // div_ceil is used so that if window_size divides 32, num_partitions will return the quotient
+    // But if window_size does not divide 32, then num_partitions will round up instead of round down, the default in Rust
+    let num_partitions = 32usize.div_ceil(window_size);
 
     // Vector to hold information on partitions
     let mut partitions = Vec::new();
@@ -44,41 +68,55 @@ pub fn partition_msm(scalars: &[u32], window_size: usize) -> Vec<MsmPartition> {
 
         // Push the partition information to the list of partitions
         partitions.push(MsmPartition { bit_index, window_values });
-        // let duration_partitioning = start_partitioning.elapsed();
-        // println!("Partitioning took: {:?}", duration_partitioning);
     }
 
     // Return the list of partitions, each containing its bit index and window values
     partitions
 }
 
-pub fn compute_msm_for_partition(partition: &MsmPartition, points: &[G1Projective], window_size: usize) -> G1Projective {
-    // let start_bucketing = Instant::now();
+// Step 1 for field scalars: Split MSM with b-bit scalars into b/c MSMs, where b is the bit size of the field modulus
+pub fn partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<MsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_partitions = num_partitions(F::MODULUS_BIT_SIZE as usize, window_size);
+    let mut partitions = Vec::new();
+
+    for partition_index in 0..num_partitions {
+        let bit_index = partition_index * window_size;
+        let window_values: Vec<u32> = bigints.iter().map(|scalar| {
+            bigint_window(scalar, bit_index, window_size)
+        }).collect();
+        partitions.push(MsmPartition { bit_index, window_values });
+    }
+
+    partitions
+}
+
+pub fn compute_msm_for_partition<G: CurveGroup>(partition: &MsmPartition, points: &[G], window_size: usize) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
     let mut buckets: HashMap<u32, Vec<usize>> = HashMap::new();
     for (index, &value) in partition.window_values.iter().enumerate() {
         if value != 0 {
-            buckets.entry(value).or_insert_with(Vec::new).push(index);
+            buckets.entry(value).or_default().push(index);
         }
     }
-    // let duration_bucketing = start_bucketing.elapsed();
-    // println!("Bucketing took: {:?}", duration_bucketing);
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
 
     let max_scalar_value = (1 << window_size) - 1;
-    let mut msm_result = G1Projective::zero();
-    let mut temp = G1Projective::zero();
+    let mut msm_result = G::zero();
+    let mut temp = G::zero();
 
-    // let start_subsum = Instant::now();
     for scalar_value in (1..=max_scalar_value).rev() {
         if let Some(indexes) = buckets.get(&scalar_value) {
-            let sum_of_points: G1Projective = indexes.iter()
+            let sum_of_points: G = indexes.iter()
                 .map(|&i| points[i])
-                .fold(G1Projective::zero(), |acc, p| add_points(acc, p));
+                .fold(G::zero(), add_points);
             temp = add_points(temp, sum_of_points);
         }
         msm_result = add_points(msm_result, temp);
     }
-    // let duration_subsum = start_iterating.elapsed();
-    // println!("Subsum accumulation took: {:?}", duration_subsum);
 
     msm_result
 }
@@ -86,18 +124,19 @@ pub fn compute_msm_for_partition(partition: &MsmPartition, points: &[G1Projectiv
 
 
 // Step 3: Compute the final MSM result by combining all partitions
-pub fn combine_partitioned_msm(partitions: &[MsmPartition], points: &[G1Projective], window_size: usize) -> G1Projective {
+pub fn combine_partitioned_msm<G: CurveGroup>(partitions: &[MsmPartition], points: &[G], window_size: usize) -> G {
     // Variable to store the final MSM result
-    let mut final_result = G1Projective::zero();
+    let mut final_result = G::zero();
 
     // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
     for partition in partitions.iter().rev() {
         // Computing MSM for the current partition
         let partition_msm = compute_msm_for_partition(partition, points, window_size);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
 
         // Double the final result window_size times to mimic scaling by 2^bit_index
         for _ in 0..window_size {
-            final_result = final_result.double();
+            final_result = double_point(final_result);
         }
 
         // Adding the partition MSM to the final result
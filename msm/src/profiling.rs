@@ -0,0 +1,188 @@
+use std::fmt;
+use std::time::Duration;
+#[cfg(feature = "profiling")]
+use crate::thread_pool::context_pool;
+#[cfg(feature = "profiling")]
+use std::cell::RefCell;
+#[cfg(feature = "profiling")]
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+#[cfg(feature = "profiling")]
+use std::sync::Arc;
+#[cfg(feature = "profiling")]
+use std::time::Instant;
+
+// True when the crate is built with the profiling feature, otherwise every StageTimings report is zero
+pub const PROFILING_ENABLED: bool = cfg!(feature = "profiling");
+
+// Stages shared by the bucket-based algorithms
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    // Splitting scalars into windows
+    Partition,
+    // Recoding windows into signed or sliding-window digits
+    Decompose,
+    // Assigning points to buckets and summing the points in each bucket
+    BucketFill,
+    // Running sum over the buckets of a window
+    BucketReduction,
+    // Doubling and adding the window results into the final result
+    WindowCombination,
+}
+
+#[cfg(feature = "profiling")]
+const NUM_STAGES: usize = 5;
+
+// Time spent in each stage of one MSM
+// Stages run on rayon tasks are summed over every task, so parallel variants can report more time than elapsed
+// The HashMap-based variants sum each bucket's points lazily during the running sum, so that work counts as bucket reduction
+// Stages an algorithm does not have are left at zero
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StageTimings {
+    pub partition: Duration,
+    pub decompose: Duration,
+    pub bucket_fill: Duration,
+    pub bucket_reduction: Duration,
+    pub window_combination: Duration,
+}
+
+impl StageTimings {
+    pub fn get(&self, stage: Stage) -> Duration {
+        match stage {
+            Stage::Partition => self.partition,
+            Stage::Decompose => self.decompose,
+            Stage::BucketFill => self.bucket_fill,
+            Stage::BucketReduction => self.bucket_reduction,
+            Stage::WindowCombination => self.window_combination,
+        }
+    }
+
+    // Sum over every stage
+    pub fn total(&self) -> Duration {
+        self.partition + self.decompose + self.bucket_fill + self.bucket_reduction + self.window_combination
+    }
+}
+
+impl fmt::Display for StageTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "partition: {:?}, decompose: {:?}, bucket fill: {:?}, bucket reduction: {:?}, window combination: {:?}",
+            self.partition, self.decompose, self.bucket_fill, self.bucket_reduction, self.window_combination)
+    }
+}
+
+// Nanoseconds spent in each stage of one profile, and the profile it is nested in
+#[cfg(feature = "profiling")]
+pub(crate) struct ProfileContext {
+    stage_nanos: [AtomicU64; NUM_STAGES],
+    parent: Option<Arc<ProfileContext>>,
+}
+
+#[cfg(feature = "profiling")]
+thread_local! {
+    // Profile the stage timers of this thread record into, the workers of the pool a profile runs on share it
+    static CURRENT_PROFILE: RefCell<Option<Arc<ProfileContext>>> = const { RefCell::new(None) };
+}
+
+// Number of profiles running on any thread, so stage timers outside every profile skip the thread-local lookup
+#[cfg(feature = "profiling")]
+static ACTIVE_PROFILES: AtomicUsize = AtomicUsize::new(0);
+
+// Profile of the calling thread, passed on to the workers of any pool built inside it
+#[cfg(feature = "profiling")]
+pub(crate) fn current_profile() -> Option<Arc<ProfileContext>> {
+    CURRENT_PROFILE.with(|current| current.borrow().clone())
+}
+
+// Make context the profile of the calling thread and return the previous one
+#[cfg(feature = "profiling")]
+pub(crate) fn set_current_profile(context: Option<Arc<ProfileContext>>) -> Option<Arc<ProfileContext>> {
+    CURRENT_PROFILE.with(|current| current.replace(context))
+}
+
+// Restores the previous profile of the calling thread when a profile returns or panics
+#[cfg(feature = "profiling")]
+struct EnteredProfile {
+    previous: Option<Arc<ProfileContext>>,
+}
+
+#[cfg(feature = "profiling")]
+impl Drop for EnteredProfile {
+    fn drop(&mut self) {
+        set_current_profile(self.previous.take());
+        ACTIVE_PROFILES.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Measures a stage from start until it is dropped, without the profiling feature it is empty and costs nothing
+#[must_use = "the stage is timed until the timer is dropped"]
+pub struct StageTimer {
+    #[cfg(feature = "profiling")]
+    stage: Stage,
+    #[cfg(feature = "profiling")]
+    start: Instant,
+}
+
+impl StageTimer {
+    #[inline]
+    pub fn start(stage: Stage) -> Self {
+        #[cfg(not(feature = "profiling"))]
+        let _ = stage;
+
+        StageTimer {
+            #[cfg(feature = "profiling")]
+            stage,
+            #[cfg(feature = "profiling")]
+            start: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "profiling")]
+impl Drop for StageTimer {
+    fn drop(&mut self) {
+        if ACTIVE_PROFILES.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        let nanos = self.start.elapsed().as_nanos() as u64;
+        CURRENT_PROFILE.with(|current| {
+            // Nested profiles also report the stage to every profile around them
+            let mut context = current.borrow().clone();
+            while let Some(profile) = context {
+                profile.stage_nanos[self.stage as usize].fetch_add(nanos, Ordering::Relaxed);
+                context = profile.parent.clone();
+            }
+        });
+    }
+}
+
+// Run f and report the time each stage took inside it
+// f runs on a cached rayon pool leased to this profile, whose workers record into it, so stage timers of parallel variants inside f are included
+// and stage timers on any other thread, such as a concurrent MSM, are not
+// Profiles may be nested, the stages of the inner profile are reported by the outer one as well
+pub fn profile<R: Send>(f: impl FnOnce() -> R + Send) -> (R, StageTimings) {
+    #[cfg(feature = "profiling")]
+    {
+        let context = Arc::new(ProfileContext {
+            stage_nanos: [const { AtomicU64::new(0) }; NUM_STAGES],
+            parent: current_profile(),
+        });
+        let result = {
+            ACTIVE_PROFILES.fetch_add(1, Ordering::SeqCst);
+            let _entered = EnteredProfile { previous: set_current_profile(Some(Arc::clone(&context))) };
+            let pool = context_pool(rayon::current_num_threads()).unwrap_or_else(|error| panic!("{}", error));
+            pool.install(f)
+        };
+
+        let elapsed = |stage: Stage| Duration::from_nanos(context.stage_nanos[stage as usize].load(Ordering::Relaxed));
+        let timings = StageTimings {
+            partition: elapsed(Stage::Partition),
+            decompose: elapsed(Stage::Decompose),
+            bucket_fill: elapsed(Stage::BucketFill),
+            bucket_reduction: elapsed(Stage::BucketReduction),
+            window_combination: elapsed(Stage::WindowCombination),
+        };
+        (result, timings)
+    }
+
+    #[cfg(not(feature = "profiling"))]
+    (f(), StageTimings::default())
+}
@@ -0,0 +1,68 @@
+use ark_ff::{BigInt, BigInteger};
+
+// Number of partitions needed to cover scalar_bits bits with windows of window_size bits
+// div_ceil rounds up so that a final, narrower window still covers the top bits of the scalar
+pub fn num_partitions(scalar_bits: usize, window_size: usize) -> usize {
+    scalar_bits.div_ceil(window_size)
+}
+
+// Extract window_size bits of a multi-limb scalar starting at bit_index
+pub fn bigint_window<B: BigInteger>(scalar: &B, bit_index: usize, window_size: usize) -> u32 {
+    let limbs = scalar.as_ref();
+    let limb_index = bit_index / 64;
+    let bit_offset = bit_index % 64;
+
+    // Bits past the most significant limb are zero
+    if limb_index >= limbs.len() {
+        return 0;
+    }
+
+    // Shift the containing limb to right align the desired bits
+    let mut window = limbs[limb_index] >> bit_offset;
+
+    // Pull in the low bits of the next limb if the window straddles a limb boundary
+    if bit_offset + window_size > 64 && limb_index + 1 < limbs.len() {
+        window |= limbs[limb_index + 1] << (64 - bit_offset);
+    }
+
+    // Mask off the rest, window_size is at most 32 so the result fits in a u32
+    (window & ((1u64 << window_size) - 1)) as u32
+}
+
+// Scalars of a fixed bit width that windows can be read from, so window-based decompositions work for any integer width
+pub trait WindowedScalar {
+    // Number of bits in the representation, the top bits may be zero
+    const BITS: usize;
+
+    // Extract window_size bits starting at bit_index, bits past the top of the scalar are zero
+    fn window(&self, bit_index: usize, window_size: usize) -> u32;
+}
+
+// Primitive unsigned integers are read by shifting and masking
+macro_rules! impl_windowed_scalar {
+    ($($scalar:ty),*) => {
+        $(
+            impl WindowedScalar for $scalar {
+                const BITS: usize = <$scalar>::BITS as usize;
+
+                fn window(&self, bit_index: usize, window_size: usize) -> u32 {
+                    if bit_index >= <Self as WindowedScalar>::BITS {
+                        return 0;
+                    }
+                    ((*self >> bit_index) as u64 & ((1u64 << window_size) - 1)) as u32
+                }
+            }
+        )*
+    };
+}
+
+impl_windowed_scalar!(u32, u64, u128);
+
+// Multi-limb integers such as BigInteger256 and BigInteger832, including the BigInt of every scalar field
+impl<const N: usize> WindowedScalar for BigInt<N> {
+    const BITS: usize = 64 * N;
+
+    fn window(&self, bit_index: usize, window_size: usize) -> u32 {
+        bigint_window(self, bit_index, window_size)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::operations::{add_mixed, add_points, double_point, negate_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_SID_WINDOW_SIZE};
+use crate::sid_pippenger::{sid_partition_msm, sid_partition_msm_field, sid_decompose_partitions, SidMsmPartitionDecomposed};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::AffineRepr;
+use ark_ff::Zero;
+use std::collections::HashMap;
+use std::ops::Neg;
+
+// Main function for Pippenger with Signed Integer Decomposition and affine input points
+// Negative digits negate their point, AffineRepr does not require Neg but every MNT affine point implements it
+pub fn sid_affine_pippenger<A: AffineRepr + Neg<Output = A>>(points: &[A], scalars: &[u32], window_size: usize) -> A::Group {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = sid_partition_msm(scalars, window_size);
+    let decomposed_partitions = sid_decompose_partitions(&partitions, window_size);
+    sid_affine_combine_partitioned_msm(&decomposed_partitions, points, window_size)
+}
+
+// Main function for sid_affine_pippenger with full-width field scalars
+pub fn sid_affine_pippenger_field<A: AffineRepr + Neg<Output = A>>(points: &[A], scalars: &[A::ScalarField], window_size: usize) -> A::Group {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = sid_partition_msm_field(scalars, window_size);
+    let decomposed_partitions = sid_decompose_partitions(&partitions, window_size);
+    sid_affine_combine_partitioned_msm(&decomposed_partitions, points, window_size)
+}
+
+// Fallible variant of sid_affine_pippenger that validates its inputs instead of panicking
+pub fn try_sid_affine_pippenger<A: AffineRepr + Neg<Output = A>>(points: &[A], scalars: &[u32], window_size: usize) -> Result<A::Group, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    check_scalar_width::<A::Group>(32)?;
+    Ok(sid_affine_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of sid_affine_pippenger_field that validates its inputs instead of panicking
+pub fn try_sid_affine_pippenger_field<A: AffineRepr + Neg<Output = A>>(points: &[A], scalars: &[A::ScalarField], window_size: usize) -> Result<A::Group, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    Ok(sid_affine_pippenger_field(points, scalars, window_size))
+}
+
+pub fn sid_affine_compute_msm_for_partition<A: AffineRepr + Neg<Output = A>>(partition: &SidMsmPartitionDecomposed, points: &[A], window_size: usize) -> A::Group {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
+    let mut buckets: HashMap<u32, Vec<(usize, i64)>> = HashMap::new(); // Use absolute value for keys and keep sign with index for values
+
+    // Assign points to buckets based on the absolute value while keeping track of the original value's sign
+    for (index, &value) in partition.window_values.iter().enumerate() {
+        if value != 0 {
+            let abs_value = value.unsigned_abs() as u32;
+            buckets.entry(abs_value).or_default().push((index, value));
+        }
+    }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
+
+    let max_scalar_value = 1 << (window_size - 1);
+    let mut msm_result = A::Group::zero();
+    let mut temp = A::Group::zero();
+
+    // Iterating over scalar values in decreasing order
+    for scalar_value in (1..=max_scalar_value).rev() {
+        if let Some(index_sign_pairs) = buckets.get(&scalar_value) {
+            // Negating an affine point keeps it affine, so negative digits still use mixed additions
+            let sum_of_points: A::Group = index_sign_pairs.iter()
+                .fold(A::Group::zero(), |sum, &(i, sign)| {
+                    if sign < 0 {
+                        add_mixed(sum, negate_point(points[i]))
+                    } else {
+                        add_mixed(sum, points[i])
+                    }
+                });
+
+            temp = add_points(temp, sum_of_points);
+        }
+
+        // Add temp to msm_result after each scalar value iteration
+        msm_result = add_points(msm_result, temp);
+    }
+
+    msm_result
+}
+
+pub fn sid_affine_combine_partitioned_msm<A: AffineRepr + Neg<Output = A>>(partitions: &[SidMsmPartitionDecomposed], points: &[A], window_size: usize) -> A::Group {
+    let mut final_result = A::Group::zero();
+
+    // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
+    for partition in partitions.iter().rev() {
+        let partition_msm = sid_affine_compute_msm_for_partition(partition, points, window_size);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
+
+        // Double the final result window_size times to mimic scaling by 2^bit_index
+        for _ in 0..window_size {
+            final_result = double_point(final_result);
+        }
+
+        // Add the iteratively doubled result to the accumulated result
+        final_result = add_points(final_result, partition_msm);
+    }
+
+    final_result
+}
@@ -0,0 +1,89 @@
+use crate::operations::{add_points, double_point, negate_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size_range, MsmError, MAX_FLAT_WINDOW_SIZE, MIN_SID_WINDOW_SIZE};
+use crate::batch_affine::{batch_accumulate_buckets, SwCurveGroup};
+use crate::sid_pippenger::{sid_partition_msm, sid_partition_msm_field, sid_decompose_partitions, SidMsmPartitionDecomposed};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::short_weierstrass::Affine;
+
+// Main function for Pippenger with Signed Integer Decomposition and batch-affine bucket accumulation
+pub fn sid_batch_affine_pippenger<G: SwCurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = sid_partition_msm(scalars, window_size);
+    let decomposed_partitions = sid_decompose_partitions(&partitions, window_size);
+    sid_batch_affine_combine_partitioned_msm(&decomposed_partitions, points, window_size)
+}
+
+// Main function for sid_batch_affine_pippenger with full-width field scalars
+pub fn sid_batch_affine_pippenger_field<G: SwCurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = sid_partition_msm_field(scalars, window_size);
+    let decomposed_partitions = sid_decompose_partitions(&partitions, window_size);
+    sid_batch_affine_combine_partitioned_msm(&decomposed_partitions, points, window_size)
+}
+
+// Fallible variant of sid_batch_affine_pippenger that validates its inputs instead of panicking
+pub fn try_sid_batch_affine_pippenger<G: SwCurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_SID_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(sid_batch_affine_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of sid_batch_affine_pippenger_field that validates its inputs instead of panicking
+pub fn try_sid_batch_affine_pippenger_field<G: SwCurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_SID_WINDOW_SIZE, MAX_FLAT_WINDOW_SIZE)?;
+    Ok(sid_batch_affine_pippenger_field(points, scalars, window_size))
+}
+
+pub fn sid_batch_affine_compute_msm_for_partition<G: SwCurveGroup>(partition: &SidMsmPartitionDecomposed, points: &[Affine<G::SwConfig>], window_size: usize) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
+    // Bucket i holds the points whose window value is ±(i + 1), negating an affine point only negates its y coordinate
+    let max_scalar_value = 1usize << (window_size - 1);
+    let mut buckets: Vec<Vec<Affine<G::SwConfig>>> = vec![Vec::new(); max_scalar_value];
+    for (index, &value) in partition.window_values.iter().enumerate() {
+        if value != 0 {
+            let point = if value < 0 { negate_point(points[index]) } else { points[index] };
+            buckets[value.unsigned_abs() as usize - 1].push(point);
+        }
+    }
+
+    let bucket_sums = batch_accumulate_buckets(buckets);
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
+
+    let mut msm_result = G::zero();
+    let mut temp = G::zero();
+
+    // Iterating over scalar values in decreasing order
+    for bucket_sum in bucket_sums.into_iter().rev() {
+        temp = add_points(temp, G::from_sw_affine(bucket_sum));
+        msm_result = add_points(msm_result, temp);
+    }
+
+    msm_result
+}
+
+pub fn sid_batch_affine_combine_partitioned_msm<G: SwCurveGroup>(partitions: &[SidMsmPartitionDecomposed], points: &[G], window_size: usize) -> G {
+    // Convert the points to affine form once, sharing one inversion across all of them
+    let affine_points = G::to_sw_affine(points);
+    let mut final_result = G::zero();
+
+    // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
+    for partition in partitions.iter().rev() {
+        let partition_msm: G = sid_batch_affine_compute_msm_for_partition(partition, &affine_points, window_size);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
+
+        // Double the final result window_size times to mimic scaling by 2^bit_index
+        for _ in 0..window_size {
+            final_result = double_point(final_result);
+        }
+
+        // Add the iteratively doubled result to the accumulated result
+        final_result = add_points(final_result, partition_msm);
+    }
+
+    final_result
+}
@@ -1,13 +1,14 @@
-use crate::operations::add_points;
-use ark_ec::Group;
-use ark_ff::Zero;
-use ark_mnt4_298::G1Projective;
+use crate::operations::{add_points, double_point, negate_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_SID_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions};
+use crate::signed_digits::SignedDigits;
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use std::collections::HashMap;
-use std::ops::Neg;
-// use std::time::Instant;
 
 // Main function for Pippenger with Signed Integer Decomposition Decomposition
-pub fn sid_pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -> G1Projective {
+pub fn sid_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
     
     let partitions = sid_partition_msm(scalars, window_size);
@@ -15,6 +16,44 @@ pub fn sid_pippenger(points: &[G1Projective], scalars: &[u32], window_size: usiz
     sid_combine_partitioned_msm(&decomposed_partitions, points, window_size)
 }
 
+// Main function for sid_pippenger with full-width field scalars
+pub fn sid_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = sid_partition_msm_field(scalars, window_size);
+    let decomposed_partitions = sid_decompose_partitions(&partitions, window_size);
+    sid_combine_partitioned_msm(&decomposed_partitions, points, window_size)
+}
+
+// Fallible variant of sid_pippenger that validates its inputs instead of panicking
+pub fn try_sid_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(sid_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of sid_pippenger_field that validates its inputs instead of panicking
+pub fn try_sid_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+    Ok(sid_pippenger_field(points, scalars, window_size))
+}
+
+// Main function for sid_pippenger with scalars of any width, already decomposed into signed digits
+pub fn sid_pippenger_with_digits<G: CurveGroup>(points: &[G], digits: &SignedDigits) -> G {
+    assert_eq!(points.len(), digits.num_scalars(), "Points and scalars must have the same length");
+
+    sid_combine_partitioned_msm(&digits.to_partitions(), points, digits.window_size())
+}
+
+// Fallible variant of sid_pippenger_with_digits that validates its inputs instead of panicking
+pub fn try_sid_pippenger_with_digits<G: CurveGroup>(points: &[G], digits: &SignedDigits) -> Result<G, MsmError> {
+    check_lengths(points.len(), digits.num_scalars())?;
+    check_scalar_width::<G>(digits.scalar_bits())?;
+    Ok(sid_pippenger_with_digits(points, digits))
+}
+
 pub struct SidMsmPartition {
     pub bit_index: usize,
     pub window_values: Vec<u32>,
@@ -28,7 +67,8 @@ pub struct SidMsmPartitionDecomposed {
 
 
 pub fn sid_partition_msm(scalars: &[u32], window_size: usize) -> Vec<SidMsmPartition> {
-    let num_partitions = (32 + window_size - 1) / window_size;
+    let _timer = StageTimer::start(Stage::Partition);
+    let num_partitions = 32usize.div_ceil(window_size);
     let mut partitions = Vec::new();
 
     for partition_index in 0..num_partitions {
@@ -42,9 +82,28 @@ pub fn sid_partition_msm(scalars: &[u32], window_size: usize) -> Vec<SidMsmParti
     partitions
 }
 
+// Step 1 for field scalars: Split MSM with b-bit scalars into b/c MSMs, where b is the bit size of the field modulus
+pub fn sid_partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<SidMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_partitions = num_partitions(F::MODULUS_BIT_SIZE as usize, window_size);
+    let mut partitions = Vec::new();
+
+    for partition_index in 0..num_partitions {
+        let bit_index = partition_index * window_size;
+        let window_values: Vec<u32> = bigints.iter().map(|scalar| {
+            bigint_window(scalar, bit_index, window_size)
+        }).collect();
+        partitions.push(SidMsmPartition { bit_index, window_values });
+    }
+
+    partitions
+}
+
 // Signed Integer Decomposition Step
 pub fn sid_decompose_partitions(partitions: &[SidMsmPartition], window_size: usize) -> Vec<SidMsmPartitionDecomposed> {
-    // let start_decomposition = Instant::now();
+    let _timer = StageTimer::start(Stage::Decompose);
     let base = 2u32.pow(window_size as u32);
     let threshold = base / 2;
 
@@ -56,15 +115,20 @@ pub fn sid_decompose_partitions(partitions: &[SidMsmPartition], window_size: usi
         })
         .collect();
 
-    // Append an extra partition for overflow handling
-    let last_bit_index = partitions.last().unwrap().bit_index + window_size;
-    decomposed_partitions.push(SidMsmPartitionDecomposed {
-        bit_index: last_bit_index,
-        window_values: vec![0i64; partitions[0].window_values.len()], // Initialise with zeros for overflow handling
-    });
+    // Without partitions there are no window values to decompose
+    let Some(last_partition) = partitions.last() else {
+        return decomposed_partitions;
+    };
+    let num_scalars = last_partition.window_values.len();
+
+    // Extra partition for overflow handling
+    let mut overflow_partition = SidMsmPartitionDecomposed {
+        bit_index: last_partition.bit_index + window_size,
+        window_values: vec![0i64; num_scalars], // Initialise with zeros for overflow handling
+    };
 
     // Iterate through each position of window values across all partitions
-    for i in 0..partitions[0].window_values.len() {
+    for i in 0..num_scalars {
         let mut carry = 0i64;
 
         for j in 0..partitions.len() {
@@ -81,45 +145,49 @@ pub fn sid_decompose_partitions(partitions: &[SidMsmPartition], window_size: usi
             }
         }
 
-        if carry > 0 {
-            decomposed_partitions[partitions.len()].window_values[i] += carry;
-        }
+        overflow_partition.window_values[i] = carry;
+    }
+
+    // A top window narrower than window_size - 1 bits never carries, so the overflow partition is only kept when a carry reaches it
+    if overflow_partition.window_values.iter().any(|&value| value != 0) {
+        decomposed_partitions.push(overflow_partition);
     }
-    // let duration_decomposition = start_decomposition.elapsed();
-    // println!("Decomposition took: {:?}", duration_decomposition);
 
     decomposed_partitions
 }
 
-pub fn sid_compute_msm_for_partition(partition: &SidMsmPartitionDecomposed, points: &[G1Projective], window_size: usize) -> G1Projective {
+pub fn sid_compute_msm_for_partition<G: CurveGroup>(partition: &SidMsmPartitionDecomposed, points: &[G], window_size: usize) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
     let mut buckets: HashMap<u32, Vec<(usize, i64)>> = HashMap::new(); // Use absolute value for keys and keep sign with index for values
 
     // Assign points to buckets based on the absolute value while keeping track of the original value's sign
     for (index, &value) in partition.window_values.iter().enumerate() {
         if value != 0 {
-            let abs_value = value.abs() as u32; // Use absolute value for bucket key
-            buckets.entry(abs_value).or_insert_with(Vec::new).push((index, value));
+            let abs_value = value.unsigned_abs() as u32; // Use absolute value for bucket key
+            buckets.entry(abs_value).or_default().push((index, value));
         }
     }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
 
     // Calculate the maximum scalar value based on the absolute values
     let max_scalar_value = 1 << (window_size - 1);
 
-    let mut msm_result = G1Projective::zero();
-    let mut temp = G1Projective::zero();
+    let mut msm_result = G::zero();
+    let mut temp = G::zero();
 
     // Iterating over scalar values in decreasing order
     for scalar_value in (1..=max_scalar_value).rev() {
         if let Some(index_sign_pairs) = buckets.get(&scalar_value) {
-            let sum_of_points: G1Projective = index_sign_pairs.iter()
+            let sum_of_points: G = index_sign_pairs.iter()
                 .map(|&(i, sign)| {
                     let mut point = points[i];
                     if sign < 0 {
-                        point = point.neg(); // Negate the point if the original value was negative
+                        point = negate_point(point); // Negate the point if the original value was negative
                     }
                     point
                 })
-                .fold(G1Projective::zero(), |acc, p| add_points(acc, p));
+                .fold(G::zero(), add_points);
 
             temp = add_points(temp, sum_of_points);
         }
@@ -132,17 +200,18 @@ pub fn sid_compute_msm_for_partition(partition: &SidMsmPartitionDecomposed, poin
 }
 
 
-pub fn sid_combine_partitioned_msm(partitions: &[SidMsmPartitionDecomposed], points: &[G1Projective], window_size: usize) -> G1Projective {
+pub fn sid_combine_partitioned_msm<G: CurveGroup>(partitions: &[SidMsmPartitionDecomposed], points: &[G], window_size: usize) -> G {
     
-    let mut final_result = G1Projective::zero();
+    let mut final_result = G::zero();
    
     // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
     for partition in partitions.iter().rev() {
         let partition_msm = sid_compute_msm_for_partition(partition, points, window_size);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
 
         // Double the final result window_size times to mimic scaling by 2^bit_index
         for _ in 0..window_size {
-            final_result = final_result.double();
+            final_result = double_point(final_result);
         }
 
         // Add the iteratively doubled result to the accumulated result
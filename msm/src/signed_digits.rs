@@ -0,0 +1,139 @@
+use crate::error::{check_window_size, MsmError, MAX_WINDOW_SIZE, MIN_SID_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions, WindowedScalar};
+use crate::sid_pippenger::SidMsmPartitionDecomposed;
+use crate::profiling::{Stage, StageTimer};
+use ark_ff::PrimeField;
+
+// Signed Integer Decomposition of a batch of scalars of any bit width, reusable by any bucket-based algorithm
+// Every digit lies in -2^(window_size - 1)..=2^(window_size - 1) and scalar i = sum of digit(i, k) * 2^(k * window_size)
+pub struct SignedDigits {
+    window_size: usize,
+    scalar_bits: usize,
+    num_windows: usize,
+    num_scalars: usize,
+    // digits[k * num_scalars + i] = digit k of scalar i, stored window by window as the bucket passes read them
+    digits: Vec<i64>,
+}
+
+// Number of signed windows for scalars of scalar_bits bits
+// The overflow window is only needed when the top window is at least window_size - 1 bits wide, narrower top windows
+// stay below 2^(window_size - 1) even after absorbing a carry
+pub fn sid_num_windows(scalar_bits: usize, window_size: usize) -> usize {
+    let num_windows = num_partitions(scalar_bits, window_size);
+    if num_windows == 0 {
+        return 0;
+    }
+
+    let top_window_bits = scalar_bits - (num_windows - 1) * window_size;
+    if top_window_bits >= window_size - 1 {
+        num_windows + 1
+    } else {
+        num_windows
+    }
+}
+
+impl SignedDigits {
+    // Decompose scalars over the full width of their type, e.g. 64 bits for u64 or 256 bits for BigInteger256
+    pub fn new<S: WindowedScalar>(scalars: &[S], window_size: usize) -> Self {
+        Self::with_scalar_bits(scalars, window_size, S::BITS)
+    }
+
+    // Decompose scalars known to fit in scalar_bits bits, which can save the top windows of wide types
+    pub fn with_scalar_bits<S: WindowedScalar>(scalars: &[S], window_size: usize, scalar_bits: usize) -> Self {
+        Self::decompose(scalars.len(), window_size, scalar_bits, |i, bit_index| scalars[i].window(bit_index, window_size))
+    }
+
+    // Decompose full-width scalar field elements, which only need to cover the bits of the field modulus
+    pub fn from_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Self {
+        // Convert scalars out of Montgomery form once rather than once per window
+        let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+        Self::decompose(bigints.len(), window_size, F::MODULUS_BIT_SIZE as usize, |i, bit_index| bigint_window(&bigints[i], bit_index, window_size))
+    }
+
+    // Fallible variant of with_scalar_bits that rejects unsupported window sizes instead of panicking
+    pub fn try_with_scalar_bits<S: WindowedScalar>(scalars: &[S], window_size: usize, scalar_bits: usize) -> Result<Self, MsmError> {
+        check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+        Ok(Self::with_scalar_bits(scalars, window_size, scalar_bits))
+    }
+
+    // Fallible variant of from_field that rejects unsupported window sizes instead of panicking
+    pub fn try_from_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Result<Self, MsmError> {
+        check_window_size(window_size, MIN_SID_WINDOW_SIZE)?;
+        Ok(Self::from_field(scalars, window_size))
+    }
+
+    // Single pass over each scalar from the least significant window up, carrying into the window above
+    // window(i, bit_index) gives the unsigned window of scalar i starting at bit_index
+    fn decompose(num_scalars: usize, window_size: usize, scalar_bits: usize, window: impl Fn(usize, usize) -> u32) -> Self {
+        assert!((MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE).contains(&window_size), "Window size must lie within 2..=31");
+        let _timer = StageTimer::start(Stage::Decompose);
+
+        let base = 1i64 << window_size;
+        let threshold = base / 2;
+        let num_windows = sid_num_windows(scalar_bits, window_size);
+        let mut digits = vec![0i64; num_windows * num_scalars];
+
+        for i in 0..num_scalars {
+            let mut carry = 0i64;
+
+            for k in 0..num_windows {
+                let bit_index = k * window_size;
+                // The overflow window lies above scalar_bits and only holds the carry
+                let window_value = if bit_index < scalar_bits { window(i, bit_index) as i64 } else { 0 };
+                let adjusted_value = window_value + carry;
+
+                if adjusted_value >= threshold {
+                    digits[k * num_scalars + i] = adjusted_value - base;
+                    carry = 1;
+                } else {
+                    digits[k * num_scalars + i] = adjusted_value;
+                    carry = 0;
+                }
+            }
+        }
+
+        SignedDigits { window_size, scalar_bits, num_windows, num_scalars, digits }
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    // Widest scalars the decomposition covers
+    pub fn scalar_bits(&self) -> usize {
+        self.scalar_bits
+    }
+
+    pub fn num_windows(&self) -> usize {
+        self.num_windows
+    }
+
+    pub fn num_scalars(&self) -> usize {
+        self.num_scalars
+    }
+
+    // Bit index that window k is scaled by
+    pub fn bit_index(&self, window: usize) -> usize {
+        window * self.window_size
+    }
+
+    // Digits of every scalar in window k
+    pub fn window(&self, window: usize) -> &[i64] {
+        &self.digits[window * self.num_scalars..(window + 1) * self.num_scalars]
+    }
+
+    // Digit k of scalar i
+    pub fn digit(&self, scalar: usize, window: usize) -> i64 {
+        self.window(window)[scalar]
+    }
+
+    // Partitions in the form taken by sid_combine_partitioned_msm and the other SID combine steps
+    pub fn to_partitions(&self) -> Vec<SidMsmPartitionDecomposed> {
+        (0..self.num_windows)
+            .map(|window| SidMsmPartitionDecomposed {
+                bit_index: self.bit_index(window),
+                window_values: self.window(window).to_vec(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size_range, MsmError, MIN_WINDOW_SIZE};
+use crate::window::MAX_STRAUS_WINDOW_SIZE;
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+
+// Main function for the Straus interleaved method, suited to small MSMs where Pippenger's buckets are mostly empty
+pub fn straus<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let decompose_timer = StageTimer::start(Stage::Decompose);
+    let digits: Vec<Vec<u32>> = scalars.iter()
+        .map(|&scalar| sliding_window_digits(scalar, window_size))
+        .collect();
+    drop(decompose_timer);
+    straus_combine(points, &digits, window_size)
+}
+
+// Main function for straus with full-width field scalars
+pub fn straus_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let decompose_timer = StageTimer::start(Stage::Decompose);
+    let digits: Vec<Vec<u32>> = scalars.iter()
+        .map(|scalar| sliding_window_digits_field(scalar, window_size))
+        .collect();
+    drop(decompose_timer);
+    straus_combine(points, &digits, window_size)
+}
+
+// Fallible variant of straus that validates its inputs instead of panicking
+pub fn try_straus<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_STRAUS_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(straus(points, scalars, window_size))
+}
+
+// Fallible variant of straus_field that validates its inputs instead of panicking
+pub fn try_straus_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_WINDOW_SIZE, MAX_STRAUS_WINDOW_SIZE)?;
+    Ok(straus_field(points, scalars, window_size))
+}
+
+// Step 1: Sliding window recoding, digits[i] is an odd value below 2^window_size or 0, and scalar = sum of digits[i] * 2^i
+// Scanning from the least significant bit, every set bit starts a window of window_size bits, which is odd by construction
+pub fn sliding_window_recode(bit: impl Fn(usize) -> bool, num_bits: usize, window_size: usize) -> Vec<u32> {
+    let mut digits = vec![0u32; num_bits];
+    let mut bit_index = 0;
+
+    while bit_index < num_bits {
+        if !bit(bit_index) {
+            bit_index += 1;
+            continue;
+        }
+
+        // Read the window from its most significant bit down
+        digits[bit_index] = (0..window_size).rev()
+            .fold(0, |window, offset| (window << 1) | bit(bit_index + offset) as u32);
+        bit_index += window_size;
+    }
+
+    digits
+}
+
+// Sliding window digits of a 32-bit scalar
+pub fn sliding_window_digits(scalar: u32, window_size: usize) -> Vec<u32> {
+    sliding_window_recode(|i| i < 32 && (scalar >> i) & 1 == 1, 32, window_size)
+}
+
+// Sliding window digits of a full-width field scalar
+pub fn sliding_window_digits_field<F: PrimeField>(scalar: &F, window_size: usize) -> Vec<u32> {
+    let bigint = scalar.into_bigint();
+    sliding_window_recode(|i| bigint.get_bit(i), F::MODULUS_BIT_SIZE as usize, window_size)
+}
+
+// Step 2: Precompute the odd multiples P, 3P, 5P, ..., (2^window_size - 1)P of a point
+pub fn straus_precompute_odd_multiples<G: CurveGroup>(point: G, window_size: usize) -> Vec<G> {
+    let num_multiples = 1 << (window_size - 1);
+    let doubled_point = double_point(point);
+    let mut multiples = Vec::with_capacity(num_multiples);
+    multiples.push(point);
+
+    for i in 1..num_multiples {
+        multiples.push(add_points(multiples[i - 1], doubled_point));
+    }
+
+    multiples
+}
+
+// Step 3: Walk down the bits once, doubling a single accumulator shared by every point
+// Each point only costs an addition at the bits where its sliding window digits start
+pub fn straus_combine<G: CurveGroup>(points: &[G], digits: &[Vec<u32>], window_size: usize) -> G {
+    // Without buckets, the table precomputation and the shared doubling chain all count as window combination
+    let _timer = StageTimer::start(Stage::WindowCombination);
+    let tables: Vec<Vec<G>> = points.iter()
+        .map(|&point| straus_precompute_odd_multiples(point, window_size))
+        .collect();
+    let num_bits = digits.iter().map(|digits| digits.len()).max().unwrap_or(0);
+
+    let mut result = G::zero();
+    for bit_index in (0..num_bits).rev() {
+        result = double_point(result);
+
+        for (table, digits) in tables.iter().zip(digits) {
+            let digit = digits[bit_index];
+            if digit != 0 {
+                // Odd digit d is stored at index (d - 1) / 2
+                result = add_points(result, table[(digit as usize - 1) / 2]);
+            }
+        }
+    }
+
+    result
+}
@@ -1,11 +1,13 @@
-use crate::operations::add_points;
-use ark_ec::Group;
-use ark_ff::Zero;
-use ark_mnt4_298::G1Projective;
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size, MsmError, MIN_WINDOW_SIZE};
+use crate::scalar::{bigint_window, num_partitions};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
 use std::collections::BTreeMap;
 
 // Main function for Pippenger with New Subsum Accumulation
-pub fn subsum_pippenger(points: &[G1Projective], scalars: &[u32], window_size: usize) -> G1Projective {
+pub fn subsum_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
 
     // Ensure points and scalars have the same length
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
@@ -14,6 +16,29 @@ pub fn subsum_pippenger(points: &[G1Projective], scalars: &[u32], window_size: u
     subsum_combine_partitioned_msm(&partitions, points, window_size)
 }
 
+// Main function for subsum_pippenger with full-width field scalars
+pub fn subsum_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let partitions = subsum_partition_msm_field(scalars, window_size);
+    subsum_combine_partitioned_msm(&partitions, points, window_size)
+}
+
+// Fallible variant of subsum_pippenger that validates its inputs instead of panicking
+pub fn try_subsum_pippenger<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(subsum_pippenger(points, scalars, window_size))
+}
+
+// Fallible variant of subsum_pippenger_field that validates its inputs instead of panicking
+pub fn try_subsum_pippenger_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size(window_size, MIN_WINDOW_SIZE)?;
+    Ok(subsum_pippenger_field(points, scalars, window_size))
+}
+
 pub struct SubsumMsmPartition {
     pub bit_index: usize,
     pub window_values: Vec<u32>,
@@ -21,11 +46,12 @@ pub struct SubsumMsmPartition {
 
 // Step 1: Split MSM with 32-bit scalars into 32/c MSMs with c-bit scalars. c == window_size
 pub fn subsum_partition_msm(scalars: &[u32], window_size: usize) -> Vec<SubsumMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
     
     // Calculate the total number of partitions based on window size
-    // (32 + window_size - 1) / window_size is used so that if 32 divides window_size, num_partitions will return the divisor
-    // But if 32 does not divide window_size, then num_partitions will round up the float instead of round down, the default in Rust
-    let num_partitions = (32 + window_size - 1) / window_size;
+    // div_ceil is used so that if window_size divides 32, num_partitions will return the quotient
+    // But if window_size does not divide 32, then num_partitions will round up instead of round down, the default in Rust
+    let num_partitions = 32usize.div_ceil(window_size);
 
     // Vector to hold information on partitions
     let mut partitions = Vec::new();
@@ -47,8 +73,28 @@ pub fn subsum_partition_msm(scalars: &[u32], window_size: usize) -> Vec<SubsumMs
     partitions
 }
 
+// Step 1 for field scalars: Split MSM with b-bit scalars into b/c MSMs, where b is the bit size of the field modulus
+pub fn subsum_partition_msm_field<F: PrimeField>(scalars: &[F], window_size: usize) -> Vec<SubsumMsmPartition> {
+    let _timer = StageTimer::start(Stage::Partition);
+    // Convert scalars out of Montgomery form once rather than once per partition
+    let bigints: Vec<F::BigInt> = scalars.iter().map(|scalar| scalar.into_bigint()).collect();
+    let num_partitions = num_partitions(F::MODULUS_BIT_SIZE as usize, window_size);
+    let mut partitions = Vec::new();
+
+    for partition_index in 0..num_partitions {
+        let bit_index = partition_index * window_size;
+        let window_values: Vec<u32> = bigints.iter().map(|scalar| {
+            bigint_window(scalar, bit_index, window_size)
+        }).collect();
+        partitions.push(SubsumMsmPartition { bit_index, window_values });
+    }
+
+    partitions
+}
+
 // Step 2: Compute MSM for each partition using subsum accumulation
-pub fn subsum_compute_msm_for_partition(partition: &SubsumMsmPartition, points: &[G1Projective]) -> G1Projective {
+pub fn subsum_compute_msm_for_partition<G: CurveGroup>(partition: &SubsumMsmPartition, points: &[G]) -> G {
+    let fill_timer = StageTimer::start(Stage::BucketFill);
     let mut buckets: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
 
     // Add an empty bucket with index 0 as requirement for new subsum accumulation algorithm
@@ -57,9 +103,11 @@ pub fn subsum_compute_msm_for_partition(partition: &SubsumMsmPartition, points:
     // Populate buckets with indexes grouped by their scalar value
     for (index, &value) in partition.window_values.iter().enumerate() {
         if value != 0 {
-            buckets.entry(value).or_insert_with(Vec::new).push(index);
+            buckets.entry(value).or_default().push(index);
         }
     }
+    drop(fill_timer);
+    let _reduction_timer = StageTimer::start(Stage::BucketReduction);
 
     // Collect all the scalar values (keys of the buckets) into a vector
     let scalars: Vec<u32> = buckets.keys().copied().collect();
@@ -71,7 +119,7 @@ pub fn subsum_compute_msm_for_partition(partition: &SubsumMsmPartition, points:
         .unwrap_or(1) as usize;
     
     // Initialise tmp array of length max_diff + 1
-    let mut tmp = vec![G1Projective::zero(); max_diff + 1];
+    let mut tmp = vec![G::zero(); max_diff + 1];
 
     // Use a peekable iterator to keep track of the next_scalar logic
     let mut iter = buckets.iter().rev().peekable();
@@ -80,7 +128,7 @@ pub fn subsum_compute_msm_for_partition(partition: &SubsumMsmPartition, points:
     while let Some((&scalar, indexes)) = iter.next() {
         let sum_of_points = indexes.iter()
             .map(|&index| points[index])
-            .fold(G1Projective::zero(), |acc, point| add_points(acc, point));
+            .fold(G::zero(), add_points);
 
         tmp[0] = add_points(tmp[0], sum_of_points);
 
@@ -94,8 +142,8 @@ pub fn subsum_compute_msm_for_partition(partition: &SubsumMsmPartition, points:
         }
     }
 
-    let mut temp = G1Projective::zero();
-    let mut msm_result = G1Projective::zero();
+    let mut temp = G::zero();
+    let mut msm_result = G::zero();
 
     // Subsum accumulation on tmp array
     for i in (1..=max_diff).rev() {
@@ -107,18 +155,19 @@ pub fn subsum_compute_msm_for_partition(partition: &SubsumMsmPartition, points:
 }
 
 // Step 3: Compute the final MSM result by combining all partitions
-pub fn subsum_combine_partitioned_msm(partitions: &[SubsumMsmPartition], points: &[G1Projective], window_size: usize) -> G1Projective {
+pub fn subsum_combine_partitioned_msm<G: CurveGroup>(partitions: &[SubsumMsmPartition], points: &[G], window_size: usize) -> G {
     // Variable to store the final MSM result
-    let mut final_result = G1Projective::zero();
+    let mut final_result = G::zero();
 
     // Iterating over each partition in reverse to ensure doubling mimics scaling accurately
     for partition in partitions.iter().rev() {
         // Computing MSM for the current partition
         let partition_msm = subsum_compute_msm_for_partition(partition, points);
+        let _combination_timer = StageTimer::start(Stage::WindowCombination);
 
         // Double the final result window_size times to mimic scaling by 2^bit_index
         for _ in 0..window_size {
-            final_result = final_result.double();
+            final_result = double_point(final_result);
         }
 
         // Adding the partition MSM to the final result
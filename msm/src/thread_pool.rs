@@ -0,0 +1,126 @@
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+#[cfg(any(feature = "counting", feature = "profiling"))]
+use std::sync::MutexGuard;
+#[cfg(feature = "counting")]
+use crate::counting::{current_count, set_current_count};
+use crate::error::MsmError;
+#[cfg(feature = "profiling")]
+use crate::profiling::{current_profile, set_current_profile};
+
+// Pools built so far, one per thread count, so repeated MSMs reuse their workers instead of spawning new ones
+static THREAD_POOLS: OnceLock<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceLock::new();
+
+// Shared rayon pool with num_threads workers, built on first use
+pub fn thread_pool(num_threads: usize) -> Result<Arc<ThreadPool>, MsmError> {
+    let mut pools = THREAD_POOLS.get_or_init(|| Mutex::new(HashMap::new())).lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(pool) = pools.get(&num_threads) {
+        return Ok(Arc::clone(pool));
+    }
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(|error| MsmError::ThreadPoolBuild { num_threads, reason: error.to_string() })?;
+    let pool = Arc::new(pool);
+    pools.insert(num_threads, Arc::clone(&pool));
+    Ok(pool)
+}
+
+// Idle pools for counts and profiles, one list per thread count, so a measurement reuses workers instead of spawning new ones
+// Each pool is leased to a single count or profile at a time, since its workers record into that measurement only
+#[cfg(any(feature = "counting", feature = "profiling"))]
+static CONTEXT_POOLS: OnceLock<Mutex<HashMap<usize, Vec<ThreadPool>>>> = OnceLock::new();
+
+// Rayon pool leased to the operation count and profile of the thread that took it, its workers record into them until it is dropped
+#[cfg(any(feature = "counting", feature = "profiling"))]
+pub(crate) struct ContextPool {
+    pool: Option<ThreadPool>,
+    num_threads: usize,
+}
+
+#[cfg(any(feature = "counting", feature = "profiling"))]
+impl ContextPool {
+    pub(crate) fn install<R: Send>(&self, f: impl FnOnce() -> R + Send) -> R {
+        self.pool.as_ref().expect("The pool is held until the lease is dropped").install(f)
+    }
+}
+
+#[cfg(any(feature = "counting", feature = "profiling"))]
+impl Drop for ContextPool {
+    // Clear the workers' count and profile and return the pool for the next measurement
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.broadcast(|_| {
+                #[cfg(feature = "counting")]
+                set_current_count(None);
+                #[cfg(feature = "profiling")]
+                set_current_profile(None);
+            });
+            context_pools().entry(self.num_threads).or_default().push(pool);
+        }
+    }
+}
+
+#[cfg(any(feature = "counting", feature = "profiling"))]
+fn context_pools() -> MutexGuard<'static, HashMap<usize, Vec<ThreadPool>>> {
+    CONTEXT_POOLS.get_or_init(|| Mutex::new(HashMap::new())).lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// Lease an idle pool with num_threads workers, or build one if every such pool is in use,
+// and make the count and profile of the calling thread those of its workers
+#[cfg(any(feature = "counting", feature = "profiling"))]
+pub(crate) fn context_pool(num_threads: usize) -> Result<ContextPool, MsmError> {
+    let idle_pool = context_pools().get_mut(&num_threads).and_then(Vec::pop);
+    let pool = match idle_pool {
+        Some(pool) => pool,
+        None => ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|error| MsmError::ThreadPoolBuild { num_threads, reason: error.to_string() })?,
+    };
+
+    #[cfg(feature = "counting")]
+    let count = current_count();
+    #[cfg(feature = "profiling")]
+    let profile = current_profile();
+    pool.broadcast(|_| {
+        #[cfg(feature = "counting")]
+        set_current_count(count.clone());
+        #[cfg(feature = "profiling")]
+        set_current_profile(profile.clone());
+    });
+    Ok(ContextPool { pool: Some(pool), num_threads })
+}
+
+// True when the calling thread records into a count or profile, whose workers the shared pools do not know about
+#[cfg(any(feature = "counting", feature = "profiling"))]
+fn in_context() -> bool {
+    #[cfg(feature = "profiling")]
+    if current_profile().is_some() {
+        return true;
+    }
+    #[cfg(feature = "counting")]
+    if current_count().is_some() {
+        return true;
+    }
+    false
+}
+
+// Run f on the shared rayon pool with num_threads workers, or on the global rayon pool when num_threads is None
+// The parallel variants use par_iter, so any parallel MSM called inside f runs on the chosen pool
+// Inside a count or profile f runs on a pool leased to it instead, so its workers still record into it
+pub fn try_install<R: Send>(num_threads: Option<usize>, f: impl FnOnce() -> R + Send) -> Result<R, MsmError> {
+    match num_threads {
+        #[cfg(any(feature = "counting", feature = "profiling"))]
+        Some(num_threads) if in_context() => Ok(context_pool(num_threads)?.install(f)),
+        Some(num_threads) => Ok(thread_pool(num_threads)?.install(f)),
+        None => Ok(f()),
+    }
+}
+
+// Infallible variant of try_install that panics when the pool cannot be built
+pub fn install<R: Send>(num_threads: Option<usize>, f: impl FnOnce() -> R + Send) -> R {
+    try_install(num_threads, f).unwrap_or_else(|error| panic!("{}", error))
+}
@@ -1,18 +1,18 @@
-use ark_ff::Zero;
-use ark_ec::Group;
-use ark_mnt4_298::G1Projective;
-use crate::operations::add_points;
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+use crate::operations::{add_points, double_point};
+use crate::error::{check_lengths, check_scalar_width, MsmError};
 
 // Trivial approach to Multi-Scalar Multiplication using doubling and addition
-pub fn trivial_msm(points: &[G1Projective], scalars: &[u32]) -> G1Projective {
+pub fn trivial_msm<G: CurveGroup>(points: &[G], scalars: &[u32]) -> G {
     // Ensure points and scalars have the same length
     assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
 
-    let mut result = G1Projective::zero();
+    let mut result = G::zero();
 
     // Iterate over each point and scalar pair
     for (i, point) in points.iter().enumerate() {
-        let mut point_contribution = G1Projective::zero();
+        let mut point_contribution = G::zero();
         let mut scalar = scalars[i];
 
         // Start with the current point to add if the LSB of the scalar is 1
@@ -26,7 +26,7 @@ pub fn trivial_msm(points: &[G1Projective], scalars: &[u32]) -> G1Projective {
             }
 
             // Double current point regardless of bit
-            current_point = current_point.double();
+            current_point = double_point(current_point);
             scalar >>= 1; // Shift the scalar to the right for the next bit
         }
 
@@ -35,4 +35,50 @@ pub fn trivial_msm(points: &[G1Projective], scalars: &[u32]) -> G1Projective {
     }
 
     result
+}
+
+// Trivial approach to Multi-Scalar Multiplication with full-width field scalars
+pub fn trivial_msm_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField]) -> G {
+    // Ensure points and scalars have the same length
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let mut result = G::zero();
+
+    // Iterate over each point and scalar pair
+    for (i, point) in points.iter().enumerate() {
+        let mut point_contribution = G::zero();
+        let scalar = scalars[i].into_bigint();
+
+        // Start with the current point to add if the LSB of the scalar is 1
+        let mut current_point = *point;
+
+        // Process each bit from LSB to the most significant set bit
+        for bit_index in 0..scalar.num_bits() as usize {
+            if scalar.get_bit(bit_index) {
+                // Add the current point to the point_contribution if the current bit is 1
+                point_contribution = add_points(point_contribution, current_point);
+            }
+
+            // Double current point regardless of bit
+            current_point = double_point(current_point);
+        }
+
+        // Add the contribution from this point-scalar pair to the total result
+        result = add_points(result, point_contribution);
+    }
+
+    result
+}
+
+// Fallible variant of trivial_msm that validates its inputs instead of panicking
+pub fn try_trivial_msm<G: CurveGroup>(points: &[G], scalars: &[u32]) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_scalar_width::<G>(32)?;
+    Ok(trivial_msm(points, scalars))
+}
+
+// Fallible variant of trivial_msm_field that validates its inputs instead of panicking
+pub fn try_trivial_msm_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField]) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    Ok(trivial_msm_field(points, scalars))
 }
\ No newline at end of file
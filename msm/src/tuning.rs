@@ -0,0 +1,264 @@
+use ark_ec::CurveGroup;
+use ark_std::UniformRand;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::{json, Value};
+use std::any::TypeId;
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use crate::algorithm::{algorithms, MsmAlgorithm, MsmConfig};
+use crate::batch_affine::SwCurveGroup;
+use crate::error::{MsmError, MsmIoError};
+use crate::io::{read_json, write_json};
+
+// Fastest algorithm and window measured for one input size
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TuningEntry {
+    pub num_points: usize,
+    pub algorithm: String,
+    pub window_size: Option<usize>,
+}
+
+impl fmt::Display for TuningEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.window_size {
+            Some(window_size) => write!(f, "{} with window {}", self.algorithm, window_size),
+            None => write!(f, "{}", self.algorithm),
+        }
+    }
+}
+
+// Winners of one tuning run for a single curve group on the machine it ran on
+// Scalar field elements are much wider than u32 scalars and favour wider windows, so they are tuned separately
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TuningProfile {
+    // Name of the curve group given by whoever tuned it, such as "mnt4-298" for the G1 group tuned from the command line
+    // A file can hold profiles for several groups, and the name picks the one to install for a group
+    pub group: String,
+    // Winners with u32 scalars, sorted by input size
+    pub entries: Vec<TuningEntry>,
+    // Winners with scalar field elements, sorted by input size
+    pub field_entries: Vec<TuningEntry>,
+}
+
+impl TuningProfile {
+    pub fn new(group: &str, mut entries: Vec<TuningEntry>, mut field_entries: Vec<TuningEntry>) -> Self {
+        entries.sort_by_key(|entry| entry.num_points);
+        field_entries.sort_by_key(|entry| entry.num_points);
+        TuningProfile { group: group.to_string(), entries, field_entries }
+    }
+
+    // Entry for the largest measured size not above num_points, or for the smallest size when num_points is below every size
+    pub fn lookup(&self, num_points: usize, field_scalars: bool) -> Option<&TuningEntry> {
+        let entries = if field_scalars { &self.field_entries } else { &self.entries };
+        entries.iter().rev().find(|entry| entry.num_points <= num_points).or(entries.first())
+    }
+}
+
+// Grid of inputs the tuner measures
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TuningConfig {
+    // Input sizes to measure
+    pub sizes: Vec<usize>,
+    // Window sizes to try for every windowed algorithm, windows an algorithm rejects are skipped
+    pub windows: RangeInclusive<usize>,
+    // Timed runs per configuration, the median is compared
+    pub runs: usize,
+    // Also tune MSMs with scalar field elements
+    pub field_scalars: bool,
+    // Seed for the generated inputs
+    pub seed: u64,
+}
+
+impl Default for TuningConfig {
+    fn default() -> Self {
+        TuningConfig {
+            sizes: (4..=16).step_by(2).map(|log_size| 1 << log_size).collect(),
+            windows: 1..=16,
+            runs: 3,
+            field_scalars: true,
+            seed: 0,
+        }
+    }
+}
+
+// Benchmark every registered algorithm and window over the grid in config and keep the fastest for each input size
+// Every configuration is timed on the same inputs, the dispatcher itself is left out since it runs one of the others
+// The profile is stored under group, the name of the curve group G
+pub fn tune<G: SwCurveGroup>(group: &str, config: &TuningConfig) -> TuningProfile {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut entries = Vec::with_capacity(config.sizes.len());
+    let mut field_entries = Vec::with_capacity(config.sizes.len());
+
+    for &num_points in &config.sizes {
+        let points = generate_points::<G>(num_points, &mut rng);
+        let scalars: Vec<u32> = (0..num_points).map(|_| rng.gen()).collect();
+        entries.push(fastest::<G>(num_points, config, |algorithm, msm_config| algorithm.try_compute(&points, &scalars, msm_config)));
+
+        if config.field_scalars {
+            let field_scalars: Vec<G::ScalarField> = (0..num_points).map(|_| G::ScalarField::rand(&mut rng)).collect();
+            field_entries.push(fastest::<G>(num_points, config, |algorithm, msm_config| algorithm.try_compute_field(&points, &field_scalars, msm_config)));
+        }
+    }
+
+    TuningProfile::new(group, entries, field_entries)
+}
+
+// Consecutive multiples of a random point are much cheaper to generate than independent random points, used by the tuner and the benchmarks
+pub fn generate_points<G: CurveGroup>(num_points: usize, rng: &mut impl Rng) -> Vec<G> {
+    let step = G::rand(rng);
+    let mut point = G::rand(rng);
+    (0..num_points).map(|_| {
+        point += step;
+        point
+    }).collect()
+}
+
+// Windows in windows that algorithm accepts, or only None for an algorithm without a window
+pub fn candidate_windows<G: CurveGroup>(algorithm: &dyn MsmAlgorithm<G>, windows: &RangeInclusive<usize>) -> Vec<Option<usize>> {
+    match algorithm.window_range() {
+        Some(range) => windows.clone().filter(|window_size| range.contains(window_size)).map(Some).collect(),
+        None => vec![None],
+    }
+}
+
+// Time every candidate configuration with run and return the one with the lowest median runtime
+fn fastest<G: SwCurveGroup>(num_points: usize, config: &TuningConfig, run: impl Fn(&dyn MsmAlgorithm<G>, &MsmConfig) -> Result<G, MsmError>) -> TuningEntry {
+    let mut best: Option<(Duration, TuningEntry)> = None;
+
+    for algorithm in algorithms::<G>().into_iter().filter(|algorithm| algorithm.name() != "auto") {
+        for window_size in candidate_windows(algorithm.as_ref(), &config.windows) {
+            let msm_config = MsmConfig { window_size, ..MsmConfig::default() };
+            let mut durations = Vec::with_capacity(config.runs.max(1));
+            for _ in 0..config.runs.max(1) {
+                let start = Instant::now();
+                if run(algorithm.as_ref(), &msm_config).is_err() {
+                    break;
+                }
+                durations.push(start.elapsed());
+            }
+
+            // The algorithm rejected the inputs
+            if durations.is_empty() {
+                continue;
+            }
+
+            durations.sort();
+            let median = durations[durations.len() / 2];
+            if best.as_ref().is_none_or(|(best_median, _)| median < *best_median) {
+                best = Some((median, TuningEntry { num_points, algorithm: algorithm.name().to_string(), window_size }));
+            }
+        }
+    }
+
+    best.expect("Naive MSM accepts every input").1
+}
+
+// JSON profile file layout: {"profiles": [{"group": ..., "entries": [...], "field_entries": [...]}, ...]}
+// with each entry stored as {"num_points": n, "algorithm": name, "window_size": c or null}
+pub fn write_profiles<W: Write>(profiles: &[TuningProfile], writer: W) -> Result<(), MsmIoError> {
+    let entries_json = |entries: &[TuningEntry]| -> Vec<Value> {
+        entries.iter().map(|entry| json!({
+            "num_points": entry.num_points,
+            "algorithm": entry.algorithm,
+            "window_size": entry.window_size,
+        })).collect()
+    };
+    let profiles: Vec<Value> = profiles.iter().map(|profile| json!({
+        "group": profile.group,
+        "entries": entries_json(&profile.entries),
+        "field_entries": entries_json(&profile.field_entries),
+    })).collect();
+    write_json(&json!({ "profiles": profiles }), writer)
+}
+
+pub fn read_profiles<R: Read>(reader: R) -> Result<Vec<TuningProfile>, MsmIoError> {
+    let value = read_json(reader)?;
+    array(&value, "profiles")?.iter().map(|profile| {
+        let group = profile.get("group")
+            .and_then(Value::as_str)
+            .ok_or_else(|| MsmIoError::Format("Expected a \"group\" string".to_string()))?;
+        Ok(TuningProfile {
+            group: group.to_string(),
+            entries: read_entries(profile, "entries")?,
+            field_entries: read_entries(profile, "field_entries")?,
+        })
+    }).collect()
+}
+
+pub fn read_profiles_file(path: impl AsRef<Path>) -> Result<Vec<TuningProfile>, MsmIoError> {
+    read_profiles(File::open(path)?)
+}
+
+// Store profile in the file at path, replacing any earlier profile for the same group and keeping the others
+pub fn save_profile_file(profile: &TuningProfile, path: impl AsRef<Path>) -> Result<(), MsmIoError> {
+    let path = path.as_ref();
+    let mut profiles = if path.exists() { read_profiles_file(path)? } else { Vec::new() };
+    profiles.retain(|existing| existing.group != profile.group);
+    profiles.push(profile.clone());
+    write_profiles(&profiles, File::create(path)?)
+}
+
+// Array stored under key
+fn array<'a>(value: &'a Value, key: &str) -> Result<&'a Vec<Value>, MsmIoError> {
+    value.get(key)
+        .and_then(Value::as_array)
+        .ok_or_else(|| MsmIoError::Format(format!("Expected a {:?} array", key)))
+}
+
+// Entries stored under key, sorted by input size
+fn read_entries(profile: &Value, key: &str) -> Result<Vec<TuningEntry>, MsmIoError> {
+    let mut entries = array(profile, key)?.iter().map(|entry| {
+        let invalid = || MsmIoError::Format(format!("Invalid tuning entry {}", entry));
+        let num_points = entry.get("num_points").and_then(Value::as_u64).ok_or_else(invalid)?;
+        let algorithm = entry.get("algorithm").and_then(Value::as_str).ok_or_else(invalid)?;
+        // Algorithms without a window store null
+        let window_size = match entry.get("window_size") {
+            None | Some(Value::Null) => None,
+            Some(window_size) => Some(window_size.as_u64().ok_or_else(invalid)? as usize),
+        };
+        Ok::<_, MsmIoError>(TuningEntry { num_points: num_points as usize, algorithm: algorithm.to_string(), window_size })
+    }).collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.num_points);
+    Ok(entries)
+}
+
+// Profiles the dispatcher consults, at most one per curve group
+// Installed profiles are keyed by the group's TypeId, which is only compared within the running program and never stored
+static TUNING_PROFILES: RwLock<Vec<(TypeId, TuningProfile)>> = RwLock::new(Vec::new());
+
+// Make the dispatcher use profile for the curve group G, replacing any profile installed for G before
+pub fn install_profile<G: CurveGroup>(profile: TuningProfile) {
+    let mut installed = TUNING_PROFILES.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    installed.retain(|(group, _)| *group != TypeId::of::<G>());
+    installed.push((TypeId::of::<G>(), profile));
+}
+
+// Install the profile stored under group in the file at path for the curve group G
+pub fn install_profile_file<G: CurveGroup>(path: impl AsRef<Path>, group: &str) -> Result<(), MsmIoError> {
+    let profile = read_profiles_file(path)?
+        .into_iter()
+        .find(|profile| profile.group == group)
+        .ok_or_else(|| MsmIoError::Format(format!("No tuning profile for {:?}", group)))?;
+    install_profile::<G>(profile);
+    Ok(())
+}
+
+// Return the dispatcher for the curve group G to its built-in heuristic
+pub fn remove_profile<G: CurveGroup>() {
+    TUNING_PROFILES.write().unwrap_or_else(|poisoned| poisoned.into_inner()).retain(|(group, _)| *group != TypeId::of::<G>());
+}
+
+// Tuned configuration for num_points points on the curve group G, None when no profile is installed for G
+pub fn tuned_entry<G: CurveGroup>(num_points: usize, field_scalars: bool) -> Option<TuningEntry> {
+    let installed = TUNING_PROFILES.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    installed.iter()
+        .find(|(group, _)| *group == TypeId::of::<G>())
+        .and_then(|(_, profile)| profile.lookup(num_points, field_scalars))
+        .cloned()
+}
@@ -0,0 +1,105 @@
+use crate::error::{MAX_WINDOW_SIZE, MIN_SID_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::scalar::num_partitions;
+
+// Bucket method used by a Pippenger variant, which changes the cost of each window
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PippengerVariant {
+    Standard,
+    Sid,
+    Subsum,
+    SidSubsum,
+}
+
+// Choose the window size for a Pippenger variant from the number of points and the scalar bit width
+pub fn optimal_window_size(num_points: usize, scalar_bits: usize, variant: PippengerVariant) -> usize {
+    match variant {
+        PippengerVariant::Standard => heuristic_window_size(num_points, scalar_bits),
+        _ => cost_model_window_size(num_points, scalar_bits, variant),
+    }
+}
+
+// Classic heuristic: small inputs use a window of 3, larger inputs a window growing with log(n)
+pub fn heuristic_window_size(num_points: usize, scalar_bits: usize) -> usize {
+    let window_size = if num_points < 32 {
+        3
+    } else {
+        (num_points as f64).ln() as usize + 2
+    };
+
+    // A window wider than the scalars only adds empty buckets
+    window_size.clamp(MIN_WINDOW_SIZE, scalar_bits.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE))
+}
+
+// Pick the window size with the lowest estimated number of group operations
+pub fn cost_model_window_size(num_points: usize, scalar_bits: usize, variant: PippengerVariant) -> usize {
+    let min_window_size = match variant {
+        PippengerVariant::Sid | PippengerVariant::SidSubsum => MIN_SID_WINDOW_SIZE,
+        _ => MIN_WINDOW_SIZE,
+    };
+    let max_window_size = scalar_bits.clamp(min_window_size, MAX_WINDOW_SIZE);
+
+    (min_window_size..=max_window_size)
+        .min_by(|&a, &b| {
+            let cost_a = estimated_cost(num_points, scalar_bits, a, variant);
+            let cost_b = estimated_cost(num_points, scalar_bits, b, variant);
+            cost_a.total_cmp(&cost_b)
+        })
+        .unwrap_or(min_window_size)
+}
+
+// Estimated number of additions and doublings for one MSM
+pub fn estimated_cost(num_points: usize, scalar_bits: usize, window_size: usize, variant: PippengerVariant) -> f64 {
+    let n = num_points as f64;
+    let mut num_windows = num_partitions(scalar_bits, window_size) as f64;
+
+    // Signed Integer Decomposition halves the buckets but appends an overflow partition
+    let num_buckets = match variant {
+        PippengerVariant::Standard | PippengerVariant::Subsum => (1u64 << window_size) as f64 - 1.0,
+        PippengerVariant::Sid | PippengerVariant::SidSubsum => {
+            num_windows += 1.0;
+            (1u64 << (window_size - 1)) as f64
+        }
+    };
+
+    let per_window = match variant {
+        // Every point is added to a bucket, then two additions per bucket in the running sum
+        PippengerVariant::Standard | PippengerVariant::Sid => n + 2.0 * num_buckets,
+        // Subsum accumulation only visits occupied buckets, plus a running sum over the largest gap between them
+        PippengerVariant::Subsum | PippengerVariant::SidSubsum => {
+            // Expected number of occupied buckets, at least one so the gap estimate stays finite
+            let occupied = (num_buckets * (1.0 - (1.0 - 1.0 / num_buckets).powf(n))).max(1.0);
+            let largest_gap = (num_buckets / occupied * (occupied.ln() + 1.0)).min(num_buckets);
+            n + 2.0 * occupied + 2.0 * largest_gap
+        }
+    };
+
+    // Combining the windows doubles window_size times per window
+    num_windows * (per_window + window_size as f64)
+}
+
+// Largest window for the Straus and wNAF methods, each point stores 2^(window_size - 1) or 2^(window_size - 2) odd multiples
+// so wider tables exhaust memory
+pub const MAX_STRAUS_WINDOW_SIZE: usize = 8;
+
+// Choose the Straus window with the fewest additions per point
+// Precomputing the odd multiples takes 2^(window_size - 1) additions, and sliding windows start on average every window_size + 1 bits
+// The doublings are shared by every point, so unlike Pippenger the best window does not depend on the number of points
+pub fn straus_window_size(scalar_bits: usize) -> usize {
+    interleaved_window_size(scalar_bits, MIN_WINDOW_SIZE, |window_size| 1u64 << (window_size - 1))
+}
+
+// Choose the wNAF window with the fewest additions per point
+// Signed digits halve the table to 2^(window_size - 2) odd multiples for the same density of 1 in window_size + 1 digits
+pub fn wnaf_window_size(scalar_bits: usize) -> usize {
+    interleaved_window_size(scalar_bits, MIN_SID_WINDOW_SIZE, |window_size| 1u64 << (window_size - 2))
+}
+
+// Argmin over min_window_size..=MAX_STRAUS_WINDOW_SIZE of table_size(window_size) + scalar_bits / (window_size + 1)
+fn interleaved_window_size(scalar_bits: usize, min_window_size: usize, table_size: impl Fn(usize) -> u64) -> usize {
+    let max_window_size = scalar_bits.clamp(min_window_size, MAX_STRAUS_WINDOW_SIZE);
+    let cost = |window_size: usize| table_size(window_size) as f64 + scalar_bits as f64 / (window_size + 1) as f64;
+
+    (min_window_size..=max_window_size)
+        .min_by(|&a, &b| cost(a).total_cmp(&cost(b)))
+        .unwrap_or(min_window_size)
+}
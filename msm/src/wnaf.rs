@@ -0,0 +1,50 @@
+use ark_ff::{BigInteger, PrimeField};
+
+// Width-w non-adjacent form, scalar = sum of digits[i] * 2^i where every non-zero digit is odd with |digit| < 2^(window_size - 1)
+// and any window_size consecutive digits hold at most one non-zero digit, so on average only 1 in window_size + 1 digits is non-zero
+// Unlike sid_decompose_partitions the non-zero digits are not tied to fixed window boundaries
+pub fn wnaf_recode(bit: impl Fn(usize) -> bool, num_bits: usize, window_size: usize) -> Vec<i64> {
+    assert!(window_size >= 2, "wNAF needs a window size of at least 2");
+
+    // A final carry can add one digit above the top bit of the scalar
+    let mut digits = vec![0i64; num_bits + 1];
+    let base = 1i64 << window_size;
+    let half_base = base / 2;
+    let mut carry = 0i64;
+    let mut bit_index = 0;
+
+    while bit_index <= num_bits {
+        let value = bit(bit_index) as i64 + carry;
+
+        // Even positions give a zero digit and pass any carry on to the next bit
+        if value % 2 == 0 {
+            carry = value / 2;
+            bit_index += 1;
+            continue;
+        }
+
+        // Odd positions take the next window_size bits plus the carry and map them into -2^(window_size - 1)..2^(window_size - 1)
+        let window = (1..window_size).fold(value, |window, offset| window + ((bit(bit_index + offset) as i64) << offset));
+        if window >= half_base {
+            digits[bit_index] = window - base;
+            carry = 1;
+        } else {
+            digits[bit_index] = window;
+            carry = 0;
+        }
+        bit_index += window_size;
+    }
+
+    digits
+}
+
+// wNAF digits of a 32-bit scalar
+pub fn wnaf_digits(scalar: u32, window_size: usize) -> Vec<i64> {
+    wnaf_recode(|i| i < 32 && (scalar >> i) & 1 == 1, 32, window_size)
+}
+
+// wNAF digits of a full-width field scalar
+pub fn wnaf_digits_field<F: PrimeField>(scalar: &F, window_size: usize) -> Vec<i64> {
+    let bigint = scalar.into_bigint();
+    wnaf_recode(|i| bigint.get_bit(i), F::MODULUS_BIT_SIZE as usize, window_size)
+}
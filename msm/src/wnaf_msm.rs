@@ -0,0 +1,74 @@
+use crate::operations::{add_points, double_point, negate_point};
+use crate::error::{check_lengths, check_scalar_width, check_window_size_range, MsmError, MIN_SID_WINDOW_SIZE};
+use crate::window::MAX_STRAUS_WINDOW_SIZE;
+use crate::straus::straus_precompute_odd_multiples;
+use crate::wnaf::{wnaf_digits, wnaf_digits_field};
+use crate::profiling::{Stage, StageTimer};
+use ark_ec::CurveGroup;
+
+// Main function for interleaved wNAF MSM
+pub fn wnaf_msm<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let decompose_timer = StageTimer::start(Stage::Decompose);
+    let digits: Vec<Vec<i64>> = scalars.iter()
+        .map(|&scalar| wnaf_digits(scalar, window_size))
+        .collect();
+    drop(decompose_timer);
+    wnaf_combine(points, &digits, window_size)
+}
+
+// Main function for wnaf_msm with full-width field scalars
+pub fn wnaf_msm_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> G {
+    assert_eq!(points.len(), scalars.len(), "Points and scalars must have the same length");
+
+    let decompose_timer = StageTimer::start(Stage::Decompose);
+    let digits: Vec<Vec<i64>> = scalars.iter()
+        .map(|scalar| wnaf_digits_field(scalar, window_size))
+        .collect();
+    drop(decompose_timer);
+    wnaf_combine(points, &digits, window_size)
+}
+
+// Fallible variant of wnaf_msm that validates its inputs instead of panicking
+pub fn try_wnaf_msm<G: CurveGroup>(points: &[G], scalars: &[u32], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_SID_WINDOW_SIZE, MAX_STRAUS_WINDOW_SIZE)?;
+    check_scalar_width::<G>(32)?;
+    Ok(wnaf_msm(points, scalars, window_size))
+}
+
+// Fallible variant of wnaf_msm_field that validates its inputs instead of panicking
+pub fn try_wnaf_msm_field<G: CurveGroup>(points: &[G], scalars: &[G::ScalarField], window_size: usize) -> Result<G, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    check_window_size_range(window_size, MIN_SID_WINDOW_SIZE, MAX_STRAUS_WINDOW_SIZE)?;
+    Ok(wnaf_msm_field(points, scalars, window_size))
+}
+
+// Walk down the digits once with a shared doubling chain, as in straus_combine
+// wNAF digits are odd with |digit| < 2^(window_size - 1), so each point only needs P, 3P, ..., (2^(window_size - 1) - 1)P
+// and negative digits subtract the same multiple, giving half the table of the unsigned sliding window
+pub fn wnaf_combine<G: CurveGroup>(points: &[G], digits: &[Vec<i64>], window_size: usize) -> G {
+    // Without buckets, the table precomputation and the shared doubling chain all count as window combination
+    let _timer = StageTimer::start(Stage::WindowCombination);
+    let tables: Vec<Vec<G>> = points.iter()
+        .map(|&point| straus_precompute_odd_multiples(point, window_size - 1))
+        .collect();
+    let num_digits = digits.iter().map(|digits| digits.len()).max().unwrap_or(0);
+
+    let mut result = G::zero();
+    for digit_index in (0..num_digits).rev() {
+        result = double_point(result);
+
+        for (table, digits) in tables.iter().zip(digits) {
+            let digit = digits[digit_index];
+            if digit != 0 {
+                // Odd digit ±d is stored at index (d - 1) / 2
+                let multiple = table[(digit.unsigned_abs() as usize - 1) / 2];
+                result = add_points(result, if digit < 0 { negate_point(multiple) } else { multiple });
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,173 @@
+use msm::accumulator::MsmAccumulator;
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::error::MsmError;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::{PrimeField, Zero};
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+#[test]
+fn test_accumulator_with_no_pairs() {
+    let accumulator = MsmAccumulator::<G1Projective>::with_scalar_bits(4, 32);
+    assert!(accumulator.is_empty(), "A new accumulator should be empty");
+    assert_eq!(accumulator.finalize(), G1Projective::zero(), "An empty accumulator should return the zero point");
+}
+
+#[test]
+fn test_accumulator_dimensions() {
+    let accumulator = MsmAccumulator::<G1Projective>::new(16);
+    assert_eq!(accumulator.scalar_bits(), Fr::MODULUS_BIT_SIZE as usize, "Default accumulator should cover full-width field scalars");
+    assert_eq!(accumulator.num_windows(), 19, "Incorrect number of windows for 298-bit scalars");
+    assert_eq!(MsmAccumulator::<G1Projective>::with_scalar_bits(5, 32).num_windows(), 7, "Incorrect number of windows for 32-bit scalars");
+}
+
+#[test]
+fn test_try_accumulator_with_invalid_window_size() {
+    assert!(matches!(MsmAccumulator::<G1Projective>::try_with_scalar_bits(0, 32), Err(MsmError::InvalidWindowSize { window_size: 0, .. })), "Window size 0 should be rejected");
+}
+
+#[test]
+// Every window keeps 2^window_size - 1 buckets, so windows are capped like the flat bucket variants
+fn test_try_accumulator_with_oversized_window() {
+    assert_eq!(MsmAccumulator::<G1Projective>::try_new(25).err(), Some(MsmError::InvalidWindowSize { window_size: 25, min: 1, max: 24 }), "Window size 25 should be rejected");
+    // Buckets are only allocated once a window receives a value, so the largest window is cheap to create
+    assert!(MsmAccumulator::<G1Projective>::try_new(24).is_ok(), "Window size 24 should be accepted");
+}
+
+#[test]
+#[should_panic(expected = "Window size 32 is outside the supported range 1..=24")]
+fn test_accumulator_with_oversized_window() {
+    MsmAccumulator::<G1Projective>::with_scalar_bits(32, 32); // This should panic
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_accumulator_push_chunk_with_different_lengths() {
+    let mut accumulator = MsmAccumulator::<G1Projective>::with_scalar_bits(4, 32);
+    accumulator.push_chunk(&generate_points(1), &[1, 2]); // This should panic
+}
+
+#[test]
+// Narrow accumulators cannot hold 32-bit scalars
+fn test_try_accumulator_push_chunk_with_wide_scalars() {
+    let mut accumulator = MsmAccumulator::<G1Projective>::with_scalar_bits(4, 16);
+    assert_eq!(accumulator.try_push_chunk(&generate_points(1), &[1]), Err(MsmError::ScalarTooWide { scalar_bits: 32, max_bits: 16 }), "32-bit scalars should be rejected");
+}
+
+#[test]
+// Field scalars need a full-width accumulator, and both the single pair and chunk variants should say so
+fn test_try_accumulator_push_field_with_wide_scalars() {
+    let mut accumulator = MsmAccumulator::<G1Projective>::with_scalar_bits(4, 32);
+    let max_bits = Fr::MODULUS_BIT_SIZE as usize;
+    let error = MsmError::ScalarTooWide { scalar_bits: max_bits, max_bits: 32 };
+    assert_eq!(accumulator.try_push_field(generate_points(1)[0], &Fr::from(1u32)), Err(error.clone()), "Field scalars should be rejected");
+    assert_eq!(accumulator.try_push_chunk_field(&generate_points(1), &[Fr::from(1u32)]), Err(error), "Field scalars should be rejected");
+    assert_eq!(accumulator.try_push_chunk_field(&generate_points(1), &[]), Err(MsmError::LengthMismatch { points: 1, scalars: 0 }), "Different lengths should be rejected");
+    assert!(accumulator.try_push(generate_points(1)[0], 1).is_ok(), "32-bit scalars should be accepted");
+    assert_eq!(accumulator.len(), 1, "Only the accepted pair should be counted");
+}
+
+#[test]
+// Accumulators that split scalars into different windows cannot be merged
+fn test_try_accumulator_merge_with_different_windows() {
+    let mut accumulator = MsmAccumulator::<G1Projective>::with_scalar_bits(4, 32);
+    let other = MsmAccumulator::<G1Projective>::with_scalar_bits(5, 32);
+    let error = MsmError::AccumulatorMismatch { window_size: 4, num_windows: 8, other_window_size: 5, other_num_windows: 7 };
+    assert_eq!(accumulator.try_merge(&other), Err(error), "Different window sizes should be rejected");
+    assert!(accumulator.try_merge(&MsmAccumulator::new(4)).is_err(), "Different scalar widths should be rejected");
+}
+
+#[test]
+#[should_panic(expected = "Cannot merge an accumulator with 7 windows of 5 bits into one with 8 windows of 4 bits")]
+fn test_accumulator_merge_with_different_windows() {
+    let mut accumulator = MsmAccumulator::<G1Projective>::with_scalar_bits(4, 32);
+    accumulator.merge(&MsmAccumulator::with_scalar_bits(5, 32)); // This should panic
+}
+
+#[test]
+// Pairs pushed one at a time should give the same result as naive MSM over the whole input
+fn test_accumulator_push() {
+    let points = generate_points(100);
+    let scalars = generate_scalars(100);
+
+    for window_size in [1, 4, 7] {
+        let mut accumulator = MsmAccumulator::with_scalar_bits(window_size, 32);
+        for (&point, &scalar) in points.iter().zip(&scalars) {
+            accumulator.push(point, scalar);
+        }
+        assert_eq!(accumulator.len(), 100, "Every pair should be counted");
+        assert_eq!(accumulator.finalize(), naive_msm(&points, &scalars), "Accumulator with window size {} did not match naive MSM", window_size);
+    }
+}
+
+#[test]
+// Pairs added in chunks and from a lazily generated iterator should match naive MSM
+fn test_accumulator_chunks_and_iterator() {
+    let points = generate_points(90);
+    let scalars = generate_scalars(90);
+    let mut accumulator = MsmAccumulator::with_scalar_bits(5, 32);
+
+    for (point_chunk, scalar_chunk) in points[..60].chunks(16).zip(scalars[..60].chunks(16)) {
+        accumulator.push_chunk(point_chunk, scalar_chunk);
+    }
+    accumulator.extend((60..90).map(|i| (points[i], scalars[i])));
+
+    assert_eq!(accumulator.finalize(), naive_msm(&points, &scalars), "Accumulator over chunks did not match naive MSM");
+}
+
+#[test]
+fn test_accumulator_field() {
+    let mut rng = test_rng();
+    let points = generate_points(40);
+    let scalars: Vec<Fr> = (0..40).map(|_| Fr::rand(&mut rng)).collect();
+    let mut accumulator = MsmAccumulator::new(8);
+
+    accumulator.push_chunk_field(&points[..20], &scalars[..20]);
+    accumulator.extend_field(points[20..].iter().copied().zip(scalars[20..].iter().copied()));
+
+    assert_eq!(accumulator.finalize(), naive_msm_field(&points, &scalars), "Accumulator with field scalars did not match naive MSM");
+}
+
+#[test]
+// Accumulators filled separately should merge into the MSM of the combined input
+fn test_accumulator_merge() {
+    let points = generate_points(60);
+    let scalars = generate_scalars(60);
+    let mut first = MsmAccumulator::with_scalar_bits(6, 32);
+    let mut second = MsmAccumulator::with_scalar_bits(6, 32);
+
+    first.push_chunk(&points[..25], &scalars[..25]);
+    second.push_chunk(&points[25..], &scalars[25..]);
+    first.merge(&second);
+
+    assert_eq!(first.len(), 60, "Merged accumulator should count every pair");
+    assert_eq!(first.finalize(), naive_msm(&points, &scalars), "Merged accumulator did not match naive MSM");
+}
+
+#[test]
+// Merging accumulators whose buckets were allocated in different windows should keep every window
+fn test_accumulator_merge_sparse_windows() {
+    let points = generate_points(2);
+    let scalars = [1u32 << 20, 3];
+    let mut first = MsmAccumulator::with_scalar_bits(4, 32);
+    let mut second = MsmAccumulator::with_scalar_bits(4, 32);
+
+    first.push(points[0], scalars[0]);
+    second.push(points[1], scalars[1]);
+    first.merge(&second);
+    first.merge(&MsmAccumulator::with_scalar_bits(4, 32));
+
+    assert_eq!(first.finalize(), naive_msm(&points, &scalars), "Merged sparse accumulators did not match naive MSM");
+}
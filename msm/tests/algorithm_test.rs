@@ -0,0 +1,211 @@
+use msm::algorithm::{algorithms, algorithm_names, get_algorithm, select_algorithm, Auto, MsmAlgorithm, MsmConfig, STRAUS_MAX_POINTS};
+use msm::error::MsmError;
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::window::MAX_STRAUS_WINDOW_SIZE;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ec::CurveGroup;
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+use std::collections::HashSet;
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+#[test]
+// The registry should contain all twenty-one algorithms under unique names
+fn test_registry_contains_all_algorithms() {
+    let names = algorithm_names::<G1Projective>();
+    let unique_names: HashSet<&str> = names.iter().copied().collect();
+
+    assert_eq!(names.len(), 21, "Registry should contain twenty-one algorithms");
+    assert_eq!(unique_names.len(), names.len(), "Algorithm names should be unique");
+}
+
+#[test]
+// Every registered name should select the algorithm with that name
+fn test_get_algorithm_by_name() {
+    for name in algorithm_names::<G1Projective>() {
+        let algorithm = get_algorithm::<G1Projective>(name).expect("Registered algorithm should be found by name");
+        assert_eq!(algorithm.name(), name, "Selected algorithm has the wrong name");
+    }
+}
+
+#[test]
+fn test_get_algorithm_with_unknown_name() {
+    assert!(get_algorithm::<G1Projective>("bogus").is_none(), "Unknown names should not select an algorithm");
+}
+
+#[test]
+// Every algorithm should agree with naive MSM through the common interface
+fn test_compute_matches_naive() {
+    let points = generate_points(50);
+    let scalars = generate_scalars(50);
+    let config = MsmConfig { window_size: Some(3), ..MsmConfig::default() };
+    let expected_result = naive_msm(&points, &scalars);
+
+    for algorithm in algorithms::<G1Projective>() {
+        assert_eq!(algorithm.compute(&points, &scalars, &config), expected_result, "{} did not match naive MSM", algorithm.name());
+    }
+}
+
+#[test]
+// Every algorithm should agree with naive MSM for full-width field scalars
+fn test_compute_field_matches_naive() {
+    let mut rng = test_rng();
+    let points = generate_points(20);
+    let scalars: Vec<Fr> = (0..20).map(|_| Fr::rand(&mut rng)).collect();
+    let config = MsmConfig { window_size: Some(4), ..MsmConfig::default() };
+    let expected_result = naive_msm_field(&points, &scalars);
+
+    for algorithm in algorithms::<G1Projective>() {
+        assert_eq!(algorithm.compute_field(&points, &scalars, &config), expected_result, "{} did not match naive MSM", algorithm.name());
+    }
+}
+
+#[test]
+// Algorithms can be stored behind the trait object and used with the default config
+fn test_default_config() {
+    let algorithm: Box<dyn MsmAlgorithm<G1Projective>> = get_algorithm("pippenger").unwrap();
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+
+    assert_eq!(MsmConfig::default().window_size, None, "Default config should choose the window size automatically");
+    assert_eq!(algorithm.compute(&points, &scalars, &MsmConfig::default()), G1Projective::zero(), "MSM with empty lists should return the zero point");
+}
+
+#[test]
+// Every algorithm should agree with naive MSM when the window size is chosen automatically
+fn test_compute_with_automatic_window_size() {
+    let mut rng = test_rng();
+    let points = generate_points(64);
+    let scalars = generate_scalars(64);
+    let field_scalars: Vec<Fr> = (0..64).map(|_| Fr::rand(&mut rng)).collect();
+    let expected_result = naive_msm(&points, &scalars);
+    let expected_field_result = naive_msm_field(&points, &field_scalars);
+
+    for algorithm in algorithms::<G1Projective>() {
+        assert_eq!(algorithm.compute(&points, &scalars, &MsmConfig::default()), expected_result, "{} did not match naive MSM", algorithm.name());
+        assert_eq!(algorithm.compute_field(&points, &field_scalars, &MsmConfig::default()), expected_field_result, "{} did not match naive MSM", algorithm.name());
+    }
+}
+
+#[test]
+// The fallible interface should report invalid inputs for every algorithm
+fn test_try_compute_with_different_lengths() {
+    let points = generate_points(1);
+    let scalars = vec![1, 2];
+
+    for algorithm in algorithms::<G1Projective>() {
+        assert_eq!(algorithm.try_compute(&points, &scalars, &MsmConfig::default()), Err(MsmError::LengthMismatch { points: 1, scalars: 2 }), "{} should reject different lengths", algorithm.name());
+    }
+}
+
+#[test]
+// The fallible interface should succeed for valid inputs
+fn test_try_compute_matches_naive() {
+    let points = generate_points(20);
+    let scalars = generate_scalars(20);
+    let config = MsmConfig { window_size: Some(3), ..MsmConfig::default() };
+    let expected_result = naive_msm(&points, &scalars);
+
+    for algorithm in algorithms::<G1Projective>() {
+        assert_eq!(algorithm.try_compute(&points, &scalars, &config), Ok(expected_result), "{} did not match naive MSM", algorithm.name());
+    }
+}
+
+#[test]
+// The parallel variants should give the same result on a pool with a fixed number of threads
+fn test_compute_with_fixed_thread_count() {
+    let points = generate_points(50);
+    let scalars = generate_scalars(50);
+    let expected_result = naive_msm(&points, &scalars);
+
+    for num_threads in [1, 2, 4] {
+        let config = MsmConfig { window_size: Some(4), num_threads: Some(num_threads) };
+        for algorithm in algorithms::<G1Projective>() {
+            assert_eq!(algorithm.compute(&points, &scalars, &config), expected_result, "{} did not match naive MSM with {} threads", algorithm.name(), num_threads);
+        }
+    }
+}
+
+#[test]
+// The dispatcher should use Straus for small inputs and Pippenger otherwise
+fn test_select_algorithm_by_input_size() {
+    assert_eq!(select_algorithm::<G1Projective>(1).name(), "straus", "Small inputs should use Straus");
+    assert_eq!(select_algorithm::<G1Projective>(STRAUS_MAX_POINTS - 1).name(), "straus", "Inputs below the threshold should use Straus");
+    assert_eq!(select_algorithm::<G1Projective>(STRAUS_MAX_POINTS).name(), "pippenger", "Inputs at the threshold should use Pippenger");
+}
+
+#[test]
+// The dispatcher should match naive MSM on both sides of the Straus threshold
+fn test_auto_matches_naive() {
+    let auto = get_algorithm::<G1Projective>("auto").expect("Dispatcher should be registered");
+    let config = MsmConfig::default();
+
+    for num_points in [0, 1, STRAUS_MAX_POINTS - 1, STRAUS_MAX_POINTS, 100] {
+        let points = generate_points(num_points);
+        let scalars = generate_scalars(num_points);
+        assert_eq!(auto.compute(&points, &scalars, &config), naive_msm(&points, &scalars), "Dispatcher did not match naive MSM with {} points", num_points);
+    }
+}
+
+#[test]
+// A window sized for Pippenger should be clamped when the dispatcher picks Straus, instead of building huge tables
+fn test_auto_clamps_window_for_straus() {
+    let auto = get_algorithm::<G1Projective>("auto").unwrap();
+    let points = generate_points(STRAUS_MAX_POINTS - 1);
+    let scalars = generate_scalars(STRAUS_MAX_POINTS - 1);
+    let config = MsmConfig { window_size: Some(16), ..MsmConfig::default() };
+
+    assert_eq!(auto.try_compute(&points, &scalars, &config), Ok(naive_msm(&points, &scalars)), "Dispatcher should clamp the window for Straus");
+}
+
+#[test]
+// Every algorithm should accept the smallest window of its range and reject the windows just outside it
+fn test_window_range() {
+    let points = generate_points(4);
+    let scalars = generate_scalars(4);
+    let expected_result = naive_msm(&points, &scalars);
+
+    for algorithm in algorithms::<G1Projective>() {
+        let Some(range) = algorithm.window_range() else {
+            assert!(["naive", "trivial", "bos_coster", "auto"].contains(&algorithm.name()), "{} should have a window range", algorithm.name());
+            continue;
+        };
+        // The largest windows allocate millions of buckets, so only the bounded ranges of Straus and wNAF are checked at their end
+        let config = MsmConfig { window_size: Some(*range.start()), ..MsmConfig::default() };
+        assert_eq!(algorithm.try_compute(&points, &scalars, &config), Ok(expected_result), "{} should accept a window of {}", algorithm.name(), range.start());
+        if *range.end() == MAX_STRAUS_WINDOW_SIZE {
+            let config = MsmConfig { window_size: Some(MAX_STRAUS_WINDOW_SIZE), ..MsmConfig::default() };
+            assert_eq!(algorithm.try_compute(&points, &scalars, &config), Ok(expected_result), "{} should accept a window of {}", algorithm.name(), MAX_STRAUS_WINDOW_SIZE);
+        }
+        for window_size in [*range.start() - 1, *range.end() + 1] {
+            let config = MsmConfig { window_size: Some(window_size), ..MsmConfig::default() };
+            assert!(algorithm.try_compute(&points, &scalars, &config).is_err(), "{} should reject a window of {}", algorithm.name(), window_size);
+        }
+    }
+}
+
+// Helper function that only relies on CurveGroup, so the dispatcher must not require a short Weierstrass curve
+fn auto_msm<G: CurveGroup>(points: &[G], scalars: &[u32]) -> G {
+    Auto.compute(points, scalars, &MsmConfig::default())
+}
+
+#[test]
+// The dispatcher should be usable from code generic over any CurveGroup
+fn test_auto_on_curve_group() {
+    let points = generate_points(20);
+    let scalars = generate_scalars(20);
+
+    assert_eq!(auto_msm(&points, &scalars), naive_msm(&points, &scalars), "Dispatcher did not match naive MSM");
+}
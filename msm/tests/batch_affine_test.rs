@@ -0,0 +1,68 @@
+use msm::batch_affine::{batch_add_affine, batch_accumulate_buckets};
+use ark_mnt4_298::{G1Affine, G1Projective};
+use ark_ec::CurveGroup;
+use ark_std::{test_rng, UniformRand};
+
+// Helper function to generate n affine points
+fn generate_affine_points(num_points: usize) -> Vec<G1Affine> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng).into_affine()).collect()
+}
+
+#[test]
+// Independent additions of distinct points should match projective addition
+fn test_batch_add_affine() {
+    let lhs = generate_affine_points(10);
+    let rhs = generate_affine_points(20)[10..].to_vec();
+    let sums = batch_add_affine(&lhs, &rhs);
+
+    for i in 0..10 {
+        assert_eq!(sums[i], (lhs[i] + rhs[i]).into_affine(), "Incorrect sum for pair {}", i);
+    }
+}
+
+#[test]
+// The special cases of affine addition should be handled within the same batch as ordinary additions
+fn test_batch_add_affine_special_cases() {
+    let points = generate_affine_points(3);
+    let identity = G1Affine::identity();
+
+    let lhs = vec![points[0], points[1], points[2], identity, points[0], identity];
+    let rhs = vec![points[1], points[1], -points[2], points[0], identity, identity];
+    let sums = batch_add_affine(&lhs, &rhs);
+
+    assert_eq!(sums[0], (points[0] + points[1]).into_affine(), "Addition of distinct points failed");
+    assert_eq!(sums[1], (points[1] + points[1]).into_affine(), "Adding a point to itself should double it");
+    assert_eq!(sums[2], identity, "Adding a point to its negation should give the point at infinity");
+    assert_eq!(sums[3], points[0], "Adding to the point at infinity should return the other point");
+    assert_eq!(sums[4], points[0], "Adding the point at infinity should return the other point");
+    assert_eq!(sums[5], identity, "Adding two points at infinity should give the point at infinity");
+}
+
+#[test]
+fn test_batch_add_affine_with_empty_lists() {
+    let points: Vec<G1Affine> = Vec::new();
+    let sums = batch_add_affine(&points, &points);
+    assert!(sums.is_empty(), "Batch addition of no pairs should return no sums");
+}
+
+#[test]
+// Each bucket should reduce to the sum of its points, regardless of its size
+fn test_batch_accumulate_buckets() {
+    let points = generate_affine_points(20);
+    let buckets = vec![
+        Vec::new(),
+        vec![points[0]],
+        points[1..3].to_vec(),
+        points[3..10].to_vec(),
+        points[10..20].to_vec(),
+        vec![points[0], points[0], -points[0]],
+    ];
+
+    let expected_sums: Vec<G1Affine> = buckets.iter()
+        .map(|bucket| bucket.iter().fold(G1Projective::default(), |acc, &p| acc + p).into_affine())
+        .collect();
+    let bucket_sums = batch_accumulate_buckets(buckets);
+
+    assert_eq!(bucket_sums, expected_sums, "Bucket sums are incorrect");
+}
@@ -0,0 +1,175 @@
+use msm::batch_pippenger::{batch_pippenger, batch_pippenger_field, try_batch_pippenger, batch_combine_partitioned_msm};
+use msm::pippenger::partition_msm;
+use msm::flat_pippenger::flat_pippenger;
+use msm::counting::count_operations;
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::thread_pool::install;
+use msm::error::{MsmError, MAX_FLAT_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+fn test_batch_pippenger_with_empty_batch() {
+    let points = generate_points(5);
+    let scalars_batch: Vec<Vec<u32>> = Vec::new();
+    let window_size = 3;
+    assert!(batch_pippenger(&points, &scalars_batch, window_size).is_empty(), "An empty batch should give no results");
+}
+
+#[test]
+fn test_batch_pippenger_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars_batch: Vec<Vec<u32>> = vec![Vec::new(), Vec::new()];
+    let window_size = 3;
+    assert_eq!(batch_pippenger(&points, &scalars_batch, window_size), vec![G1Projective::zero(); 2], "MSMs with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_batch_pippenger_with_different_lengths() {
+    let points = generate_points(2);
+    let scalars_batch = vec![vec![1, 2], vec![1]];
+    let window_size = 3;
+    let panic_result = batch_pippenger(&points, &scalars_batch, window_size); // This should panic
+    assert!(panic_result.is_empty())
+}
+
+#[test]
+fn test_try_batch_pippenger_with_different_lengths() {
+    let points = generate_points(2);
+    let scalars_batch = vec![vec![1, 2], vec![1]];
+    assert_eq!(try_batch_pippenger(&points, &scalars_batch, 3), Err(MsmError::LengthMismatch { points: 2, scalars: 1 }), "Every scalar vector should be checked against the points");
+}
+
+#[test]
+// Slices of scalars are accepted as well as vectors
+fn test_batch_pippenger_with_slices() {
+    let points = generate_points(10);
+    let scalars_1 = generate_scalars(10);
+    let scalars_2 = generate_scalars(10);
+    let window_size = 4;
+
+    let results = batch_pippenger(&points, &[&scalars_1[..], &scalars_2[..]], window_size);
+    assert_eq!(results, vec![naive_msm(&points, &scalars_1), naive_msm(&points, &scalars_2)], "Batch results did not match naive MSM");
+}
+
+#[test]
+// Test for the combine step on partitions built separately for each scalar vector
+fn test_batch_combine_partitioned_msm() {
+    let points = generate_points(10);
+    let scalars_batch: Vec<Vec<u32>> = (0..3).map(|_| generate_scalars(10)).collect();
+    let window_size = 5;
+
+    let batch_partitions: Vec<_> = scalars_batch.iter().map(|scalars| partition_msm(scalars, window_size)).collect();
+    let results = batch_combine_partitioned_msm(&batch_partitions, &points, window_size);
+
+    for (result, scalars) in results.iter().zip(&scalars_batch) {
+        // Compare against result from naive msm
+        assert_eq!(*result, naive_msm(&points, scalars), "Combined MSM result is incorrect");
+    }
+}
+
+#[test]
+// "Comprehensive test with 100 points and a batch of 8 scalar vectors"
+fn test_batch_pippenger_algorithm() {
+    let points = generate_points(100);
+    let scalars_batch: Vec<Vec<u32>> = (0..8).map(|_| generate_scalars(100)).collect();
+
+    for window_size in [1, 4, 8] {
+        let results = batch_pippenger(&points, &scalars_batch, window_size);
+        assert_eq!(results.len(), scalars_batch.len(), "There should be one result per scalar vector");
+
+        for (result, scalars) in results.iter().zip(&scalars_batch) {
+            // Compare against result from naive msm
+            assert_eq!(*result, naive_msm(&points, scalars), "Batch Pippenger with window size {} did not match expected result", window_size);
+        }
+    }
+}
+
+#[test]
+// "Comprehensive test with 30 points and a batch of 4 full-width field scalar vectors"
+fn test_batch_pippenger_field_algorithm() {
+    let points = generate_points(30);
+    let scalars_batch: Vec<Vec<Fr>> = (0..4).map(|_| generate_field_scalars(30)).collect();
+    let window_size = 6;
+
+    let results = batch_pippenger_field(&points, &scalars_batch, window_size);
+    for (result, scalars) in results.iter().zip(&scalars_batch) {
+        // Compare against result from naive msm
+        assert_eq!(*result, naive_msm_field(&points, scalars), "Batch Pippenger with field scalars did not match expected result");
+    }
+}
+
+#[test]
+// Results should not depend on the size of the pool the batch runs on
+fn test_batch_pippenger_with_fixed_thread_count() {
+    let points = generate_points(40);
+    let scalars_batch: Vec<Vec<u32>> = (0..5).map(|_| generate_scalars(40)).collect();
+    let window_size = 4;
+    let expected_results: Vec<G1Projective> = scalars_batch.iter().map(|scalars| naive_msm(&points, scalars)).collect();
+
+    for num_threads in [1, 2, 4] {
+        let results = install(Some(num_threads), || batch_pippenger(&points, &scalars_batch, window_size));
+        assert_eq!(results, expected_results, "Batch Pippenger did not match naive MSM with {} threads", num_threads);
+    }
+}
+
+#[test]
+// Each MSM in the batch fills flat buckets, so the batch should do exactly the additions and doublings of separate flat Pippenger MSMs
+fn test_batch_pippenger_matches_flat_pippenger_operations() {
+    let points = generate_points(200);
+    let scalars_batch: Vec<Vec<u32>> = (0..3).map(|_| generate_scalars(200)).collect();
+    let window_size = 4;
+
+    let (results, batch_counts) = count_operations(|| batch_pippenger(&points, &scalars_batch, window_size));
+    let (separate_results, separate_counts) = count_operations(|| {
+        scalars_batch.iter().map(|scalars| flat_pippenger(&points, scalars, window_size)).collect::<Vec<_>>()
+    });
+
+    assert_eq!(results, separate_results, "Batch results did not match separate flat Pippenger MSMs");
+    assert_eq!(batch_counts.additions, separate_counts.additions, "Bucket accumulation should not change");
+    assert_eq!(batch_counts.doublings, separate_counts.doublings, "Window combination should not change");
+}
+
+#[test]
+// Batches with fewer windows than threads are split across members, which should not change the results
+fn test_batch_pippenger_splits_members() {
+    let points = generate_points(30);
+    let scalars_batch: Vec<Vec<u32>> = (0..7).map(|_| generate_scalars(30)).collect();
+    let window_size = 11;
+    let expected_results: Vec<G1Projective> = scalars_batch.iter().map(|scalars| naive_msm(&points, scalars)).collect();
+
+    for num_threads in [1, 3, 8] {
+        let results = install(Some(num_threads), || batch_pippenger(&points, &scalars_batch, window_size));
+        assert_eq!(results, expected_results, "Batch Pippenger did not match naive MSM with {} threads", num_threads);
+    }
+}
+
+#[test]
+// Every MSM allocates a flat bucket array per window, so windows above the flat cap are rejected
+fn test_try_batch_pippenger_with_oversized_window() {
+    let points = generate_points(2);
+    let window_size = MAX_FLAT_WINDOW_SIZE + 1;
+
+    assert_eq!(try_batch_pippenger(&points, &[vec![1, 2]], window_size), Err(MsmError::InvalidWindowSize { window_size, min: MIN_WINDOW_SIZE, max: MAX_FLAT_WINDOW_SIZE }), "Window size above the cap should be rejected");
+}
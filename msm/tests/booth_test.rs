@@ -0,0 +1,144 @@
+use msm::booth::{booth_pippenger, booth_pippenger_field, try_booth_pippenger, booth_digit, booth_num_windows, booth_partition_msm, booth_partition_msm_field};
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::error::MsmError;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::{PrimeField, Zero};
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+#[test]
+fn test_booth_pippenger_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];
+    let window_size = 2;
+    assert_eq!(booth_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Pippenger with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_booth_pippenger_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 4;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(booth_pippenger(&points, &scalars, window_size), expected_result, "Pippenger with maximum scalars failed");
+}
+
+#[test]
+fn test_booth_pippenger_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 2;
+    assert_eq!(booth_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Pippenger with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_booth_pippenger_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = booth_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+fn test_try_booth_pippenger_with_window_size_one() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1];
+    assert_eq!(try_booth_pippenger(&points, &scalars, 1), Err(MsmError::InvalidWindowSize { window_size: 1, min: 2, max: 31 }), "Window size 1 should be rejected");
+}
+
+#[test]
+// Windows of 3 bits plus the borrowed bit: 0b0111 reads as 3 + 1 and 0b1000 as -8
+fn test_booth_digit() {
+    assert_eq!(booth_digit(0b0000, 3), 0, "Incorrect digit for an empty window");
+    assert_eq!(booth_digit(0b0111, 3), 4, "Incorrect digit with a borrowed bit");
+    assert_eq!(booth_digit(0b1000, 3), -4, "Incorrect digit with the sign bit set");
+    assert_eq!(booth_digit(0b1111, 3), 0, "A window of ones with a borrowed bit should cancel");
+}
+
+#[test]
+// The top window needs a zero sign bit, so there is one extra window when the window size divides the scalar width
+fn test_booth_num_windows() {
+    assert_eq!(booth_num_windows(32, 4), 9, "Incorrect number of windows for 32-bit scalars");
+    assert_eq!(booth_num_windows(32, 5), 7, "Incorrect number of windows for 32-bit scalars");
+    assert_eq!(booth_num_windows(Fr::MODULUS_BIT_SIZE as usize, 16), 19, "Incorrect number of windows for field scalars");
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+#[test]
+// Every digit should lie within -2^(c-1)..=2^(c-1) and the digits should reconstruct the scalar
+fn test_booth_partition_msm_reconstructs_scalars() {
+    let scalars: Vec<u32> = generate_scalars(100).into_iter().chain([0, 1, u32::MAX, 1 << 31]).collect();
+
+    for window_size in 2..=16 {
+        let partitions = booth_partition_msm(&scalars, window_size);
+        let bound = 1i64 << (window_size - 1);
+
+        for (i, &scalar) in scalars.iter().enumerate() {
+            let mut reconstructed = 0i128;
+            for partition in &partitions {
+                let digit = partition.window_values[i];
+                assert!((-bound..=bound).contains(&digit), "Digit {} is out of range for window size {}", digit, window_size);
+                reconstructed += (digit as i128) << partition.bit_index;
+            }
+            assert_eq!(reconstructed, scalar as i128, "Digits do not reconstruct {} with window size {}", scalar, window_size);
+        }
+    }
+}
+
+#[test]
+// Field digits should reconstruct the scalar when recombined in the field
+fn test_booth_partition_msm_field_reconstructs_scalars() {
+    let mut rng = test_rng();
+    let scalars: Vec<Fr> = (0..20).map(|_| Fr::rand(&mut rng)).collect();
+    let window_size = 7;
+    let partitions = booth_partition_msm_field(&scalars, window_size);
+
+    for (i, &scalar) in scalars.iter().enumerate() {
+        let reconstructed = partitions.iter().rev().fold(Fr::zero(), |acc, partition| {
+            let digit = partition.window_values[i];
+            let digit = if digit < 0 { -Fr::from(digit.unsigned_abs()) } else { Fr::from(digit as u64) };
+            acc * Fr::from(1u64 << window_size) + digit
+        });
+        assert_eq!(reconstructed, scalar, "Digits do not reconstruct the field scalar");
+    }
+}
+
+#[test]
+// "Comprehensive test with 200 points"
+fn test_booth_pippenger_algorithm() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..200).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars = generate_scalars(200);
+    let expected_result = naive_msm(&points, &scalars);
+
+    for window_size in 2..=12 {
+        // Compare against result from naive msm
+        assert_eq!(booth_pippenger(&points, &scalars, window_size), expected_result, "Booth Pippenger with window size {} did not match expected result", window_size);
+    }
+}
+
+#[test]
+// "Comprehensive test with 50 points and full-width field scalars"
+fn test_booth_pippenger_field_algorithm() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..50).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<Fr> = (0..50).map(|_| Fr::rand(&mut rng)).collect();
+    let expected_result = naive_msm_field(&points, &scalars);
+
+    for window_size in [2, 5, 8, 16] {
+        // Compare against result from naive msm
+        assert_eq!(booth_pippenger_field(&points, &scalars, window_size), expected_result, "Booth Pippenger with window size {} did not match expected result", window_size);
+    }
+}
@@ -0,0 +1,118 @@
+use msm::bos_coster::{bos_coster, bos_coster_field, try_bos_coster};
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::operations::{add_points, scalar_multiply};
+use msm::error::MsmError;
+use msm::counting::count_operations;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::Rng;
+
+#[test]
+fn test_bos_coster_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];  // Zero scalars
+    assert_eq!(bos_coster(&points, &scalars), G1Projective::zero(), "MSM with zero scalars should be the zero point");
+}
+
+#[test]
+fn test_bos_coster_with_all_ones_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 1];  // Equal scalars cancel in a single step
+    let expected_result = add_points(points[0], points[1]);
+    assert_eq!(bos_coster(&points, &scalars), expected_result, "MSM with all ones scalars failed");
+}
+
+#[test]
+fn test_bos_coster_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(bos_coster(&points, &scalars), expected_result, "MSM with maximum scalars failed");
+}
+
+#[test]
+// A single large scalar against a tiny one should fall back to a scalar multiplication rather than subtract 2^30 times
+fn test_bos_coster_with_unbalanced_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![1u32 << 30, 1];
+    let expected_result = add_points(scalar_multiply(points[0], Fr::from(1u32 << 30)), points[1]);
+    assert_eq!(bos_coster(&points, &scalars), expected_result, "MSM with unbalanced scalars failed");
+}
+
+#[test]
+fn test_bos_coster_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new(); // Empty list
+    let scalars: Vec<u32> = Vec::new(); // Empty list
+    assert_eq!(bos_coster(&points, &scalars), G1Projective::zero(), "MSM with empty lists should be the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_bos_coster_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let panic_result = bos_coster(&points, &scalars);  // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+fn test_try_bos_coster_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    assert_eq!(try_bos_coster(&points, &scalars), Err(MsmError::LengthMismatch { points: 1, scalars: 2 }), "Different lengths should be rejected");
+}
+
+#[test]
+fn test_bos_coster_field_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![Fr::zero(), Fr::zero()];
+    assert_eq!(bos_coster_field(&points, &scalars), G1Projective::zero(), "MSM with zero field scalars should be the zero point");
+}
+
+#[test]
+// "Comprehensive test with 200 points"
+fn test_bos_coster_algorithm() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..200).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<u32> = (0..200).map(|_| rng.gen()).collect();
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(bos_coster(&points, &scalars), expected_result, "Bos-Coster did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 50 points and full-width field scalars"
+fn test_bos_coster_field_algorithm() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..50).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<Fr> = (0..50).map(|_| Fr::rand(&mut rng)).collect();
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(bos_coster_field(&points, &scalars), expected_result, "Bos-Coster with field scalars did not match expected result");
+}
+
+#[test]
+// Random scalars should be reduced by division steps, with only a few terms finished by a scalar multiplication
+fn test_bos_coster_reduces_scalars() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..100).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<u32> = (0..100).map(|_| rng.gen()).collect();
+    let field_scalars: Vec<Fr> = (0..100).map(|_| Fr::rand(&mut rng)).collect();
+
+    let (result, counts) = count_operations(|| bos_coster(&points, &scalars));
+    assert_eq!(result, naive_msm(&points, &scalars), "Bos-Coster did not match expected result");
+    assert!(counts.scalar_multiplications < 25, "{} scalar multiplications for 100 points", counts.scalar_multiplications);
+
+    let (result, counts) = count_operations(|| bos_coster_field(&points, &field_scalars));
+    assert_eq!(result, naive_msm_field(&points, &field_scalars), "Bos-Coster with field scalars did not match expected result");
+    assert!(counts.scalar_multiplications < 25, "{} scalar multiplications for 100 points", counts.scalar_multiplications);
+}
@@ -0,0 +1,57 @@
+use msm::bucket::{flat_bucket_sums, signed_flat_bucket_sums, bucket_running_sum};
+use msm::operations::add_points;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+#[test]
+// The flat array should have 2^c buckets with each point in the bucket of its window value
+fn test_flat_bucket_sums() {
+    let points = generate_points(5);
+    let window_values = vec![3, 0, 1, 3, 7];
+    let window_size = 3;
+    let buckets = flat_bucket_sums(&window_values, &points, window_size);
+
+    assert_eq!(buckets.len(), 8, "There should be 2^window_size buckets");
+    assert_eq!(buckets[0], G1Projective::zero(), "Zero window values should not be accumulated");
+    assert_eq!(buckets[1], points[2], "Bucket 1 is incorrect");
+    assert_eq!(buckets[3], add_points(points[0], points[3]), "Bucket 3 is incorrect");
+    assert_eq!(buckets[7], points[4], "Bucket 7 is incorrect");
+    assert_eq!(buckets[2], G1Projective::zero(), "Unused buckets should stay at the zero point");
+}
+
+#[test]
+// The signed flat array should have 2^(c-1) buckets with negative window values negating their point
+fn test_signed_flat_bucket_sums() {
+    let points = generate_points(5);
+    let window_values = vec![-2, 0, 1, 2, -4];
+    let window_size = 3;
+    let buckets = signed_flat_bucket_sums(&window_values, &points, window_size);
+
+    assert_eq!(buckets.len(), 4, "There should be 2^(window_size - 1) buckets");
+    assert_eq!(buckets[0], points[2], "Bucket for ±1 is incorrect");
+    assert_eq!(buckets[1], add_points(-points[0], points[3]), "Bucket for ±2 is incorrect");
+    assert_eq!(buckets[2], G1Projective::zero(), "Unused buckets should stay at the zero point");
+    assert_eq!(buckets[3], -points[4], "Bucket for ±4 is incorrect");
+}
+
+#[test]
+// The running sum should weight bucket i by i + 1
+fn test_bucket_running_sum() {
+    let buckets = generate_points(4);
+    let expected_result = buckets.iter().enumerate()
+        .fold(G1Projective::zero(), |acc, (i, &bucket)| add_points(acc, bucket * Fr::from(i as u64 + 1)));
+    assert_eq!(bucket_running_sum(&buckets), expected_result, "Running sum is incorrect");
+}
+
+#[test]
+fn test_bucket_running_sum_with_no_buckets() {
+    let buckets: Vec<G1Projective> = Vec::new();
+    assert_eq!(bucket_running_sum(&buckets), G1Projective::zero(), "Running sum of no buckets should return the zero point");
+}
@@ -0,0 +1,91 @@
+use msm::chunked_pippenger::{chunked_pippenger, chunked_pippenger_with_chunks, chunked_pippenger_field, chunked_pippenger_field_with_chunks, chunk_size};
+use msm::naive::{naive_msm, naive_msm_field};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+fn test_chunked_pippenger_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 2;
+    assert_eq!(chunked_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Chunked Pippenger with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_chunked_pippenger_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = chunked_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+// Chunks should cover every point without any of them being empty
+fn test_chunk_size() {
+    assert_eq!(chunk_size(100, 4), 25, "Evenly divisible inputs should give equal chunks");
+    assert_eq!(chunk_size(10, 4), 3, "Chunk size should round up so there are at most num_chunks chunks");
+    assert_eq!(chunk_size(2, 8), 1, "More chunks than points should give one point per chunk");
+    assert_eq!(chunk_size(0, 4), 1, "Empty inputs should still give a non-zero chunk size");
+    assert_eq!(chunk_size(10, 0), 10, "Zero chunks should be treated as a single chunk");
+}
+
+#[test]
+// The number of chunks should not change the result, including uneven splits and more chunks than points
+fn test_chunked_pippenger_with_chunks() {
+    let points = generate_points(100);
+    let scalars = generate_scalars(100);
+    let window_size = 4;
+    let expected_result = naive_msm(&points, &scalars);
+
+    for num_chunks in [1, 3, 7, 100, 150] {
+        assert_eq!(chunked_pippenger_with_chunks(&points, &scalars, window_size, num_chunks), expected_result, "Chunked Pippenger with {} chunks did not match expected result", num_chunks);
+    }
+}
+
+#[test]
+// "Comprehensive test with 1000 points"
+fn test_chunked_pippenger_algorithm() {
+    let points = generate_points(1000);
+    let scalars = generate_scalars(1000);
+    let window_size = 16;
+
+    let msm_result = chunked_pippenger(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Chunked Pippenger did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_chunked_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+    let expected_result = naive_msm_field(&points, &scalars);
+
+    assert_eq!(chunked_pippenger_field(&points, &scalars, window_size), expected_result, "Chunked Pippenger with field scalars did not match expected result");
+    assert_eq!(chunked_pippenger_field_with_chunks(&points, &scalars, window_size, 3), expected_result, "Chunked Pippenger with field scalars and 3 chunks did not match expected result");
+}
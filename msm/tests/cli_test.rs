@@ -0,0 +1,163 @@
+use msm::io::{read_result_json, write_binary_file, write_job_json, write_scalars_csv_file, MsmJob};
+use msm::naive::{naive_msm, naive_msm_field};
+use ark_mnt4_298::{G1Affine, G1Projective, Fr};
+use ark_ec::CurveGroup;
+use ark_serialize::Compress;
+use ark_std::{test_rng, UniformRand};
+use std::fs::File;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+// Helper function to run the msm binary with the given arguments
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_msm")).args(args).output().expect("Binary should run")
+}
+
+// Helper function to give each test its own temporary file
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("msm_cli_test_{}_{}", std::process::id(), name))
+}
+
+#[test]
+// Verify should pass for every algorithm on generated inputs
+fn test_verify_all_algorithms() {
+    let output = run_cli(&["verify", "--points", "20", "--seed", "1"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success(), "Verify should succeed: {}", stdout);
+    assert!(!stdout.contains("MISMATCH"), "No algorithm should mismatch: {}", stdout);
+    assert_eq!(stdout.lines().filter(|line| line.ends_with(": ok")).count(), 21, "Every algorithm should be verified");
+}
+
+#[test]
+// Verify should pass for wide scalars on another curve with a fixed window and thread count
+fn test_verify_field_scalars() {
+    let output = run_cli(&["verify", "--algorithm", "parallel_sid_pippenger", "--points", "10", "--scalar-bits", "200", "--curve", "mnt6-298", "--window", "4", "--threads", "2", "--seed", "2"]);
+    assert!(output.status.success(), "Verify should succeed: {}", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+// The same seed should give the same result
+fn test_run_is_deterministic_for_seed() {
+    let first = run_cli(&["run", "--algorithm", "pippenger", "--points", "10", "--seed", "3"]);
+    let second = run_cli(&["run", "--algorithm", "pippenger", "--points", "10", "--seed", "3"]);
+    let result = |output: &Output| String::from_utf8_lossy(&output.stdout).lines().find(|line| line.starts_with("result:")).map(str::to_owned);
+
+    assert!(first.status.success(), "Run should succeed");
+    assert!(result(&first).is_some(), "Run should print a result");
+    assert_eq!(result(&first), result(&second), "Runs with the same seed should match");
+}
+
+#[test]
+// With "all", algorithms whose window range excludes --window should be skipped while the others still run
+fn test_all_skips_windows_out_of_range() {
+    let verify = run_cli(&["verify", "--points", "10", "--window", "1", "--seed", "6"]);
+    let stdout = String::from_utf8(verify.stdout).unwrap();
+    assert!(verify.status.success(), "Verify should succeed: {}", String::from_utf8_lossy(&verify.stderr));
+    assert!(stdout.contains("sid_pippenger: skipped (window out of range)"), "SID Pippenger should be skipped: {}", stdout);
+    assert!(stdout.lines().any(|line| line == "pippenger: ok"), "The other algorithms should still run: {}", stdout);
+
+    let bench = run_cli(&["bench", "--points", "10", "--window", "9", "--runs", "1", "--seed", "6"]);
+    let stdout = String::from_utf8(bench.stdout).unwrap();
+    assert!(bench.status.success(), "Bench should succeed: {}", String::from_utf8_lossy(&bench.stderr));
+    assert!(stdout.contains("straus: skipped (window out of range)"), "Straus should be skipped: {}", stdout);
+    assert!(stdout.lines().any(|line| line.starts_with("pippenger: ") && !line.contains("skipped")), "Pippenger should still run: {}", stdout);
+
+    let single = run_cli(&["verify", "--algorithm", "sid_pippenger", "--points", "10", "--window", "1"]);
+    assert!(!single.status.success(), "A single algorithm should still reject the window");
+}
+
+#[test]
+// Bench should report a timing for the selected algorithm
+fn test_bench() {
+    let output = run_cli(&["bench", "--algorithm", "straus", "--points", "10", "--runs", "2", "--seed", "4"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success(), "Bench should succeed");
+    assert!(stdout.lines().any(|line| line.starts_with("straus: ")), "Bench should report straus: {}", stdout);
+}
+
+#[test]
+// Unknown algorithms and invalid windows should fail with an error
+fn test_invalid_arguments() {
+    let unknown = run_cli(&["run", "--algorithm", "bogus", "--points", "1"]);
+    assert!(!unknown.status.success(), "Unknown algorithm should fail");
+    assert!(String::from_utf8_lossy(&unknown.stderr).contains("Unknown algorithm"), "Error should name the problem");
+
+    let invalid_window = run_cli(&["run", "--algorithm", "pippenger", "--points", "1", "--window", "0"]);
+    assert!(!invalid_window.status.success(), "Invalid window should fail");
+}
+
+#[test]
+// Points and scalars read from files should give the naive result
+fn test_run_from_points_and_scalars_files() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..10).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<u32> = (1..=10).collect();
+    let points_path = temp_path("points.bin");
+    let scalars_path = temp_path("scalars.csv");
+    let output_path = temp_path("points_result.json");
+    write_binary_file(&G1Projective::normalize_batch(&points), &points_path, Compress::Yes).unwrap();
+    write_scalars_csv_file(&scalars, &scalars_path).unwrap();
+
+    let output = run_cli(&["run", "--points-file", points_path.to_str().unwrap(), "--compressed", "--scalars-file", scalars_path.to_str().unwrap(), "--output", output_path.to_str().unwrap()]);
+    let result: G1Affine = read_result_json(File::open(&output_path).unwrap()).unwrap();
+
+    for path in [&points_path, &scalars_path, &output_path] {
+        std::fs::remove_file(path).unwrap();
+    }
+
+    assert!(output.status.success(), "Run should succeed: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(result, naive_msm(&points, &scalars).into_affine(), "Result did not match naive MSM");
+}
+
+#[test]
+// A JSON job file should give the naive result
+fn test_run_from_job_file() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..10).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<Fr> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+    let job = MsmJob { points: G1Projective::normalize_batch(&points), scalars: scalars.clone() };
+    let job_path = temp_path("job.json");
+    let output_path = temp_path("job_result.json");
+    write_job_json(&job, File::create(&job_path).unwrap()).unwrap();
+
+    let output = run_cli(&["run", "--algorithm", "sid_pippenger", "--job-file", job_path.to_str().unwrap(), "--output", output_path.to_str().unwrap()]);
+    let result: G1Affine = read_result_json(File::open(&output_path).unwrap()).unwrap();
+
+    for path in [&job_path, &output_path] {
+        std::fs::remove_file(path).unwrap();
+    }
+
+    assert!(output.status.success(), "Run should succeed: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(result, naive_msm_field(&points, &scalars).into_affine(), "Result did not match naive MSM");
+}
+
+#[test]
+// Every algorithm would overwrite the same output file, so run should reject --output with "all" before computing anything
+fn test_run_all_with_output() {
+    let output_path = temp_path("all_result.json");
+    let output = run_cli(&["run", "--algorithm", "all", "--points", "4", "--output", output_path.to_str().unwrap()]);
+
+    assert!(!output.status.success(), "Run should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--output writes a single result"), "The error should explain the conflict");
+    assert!(!output_path.exists(), "No result should be written");
+}
+
+#[test]
+// Tune should write a profile that run can load for the auto algorithm, and verify should still pass with it
+fn test_tune_and_run_with_profile() {
+    let profile_path = temp_path("tuning.json");
+    let tune = run_cli(&["tune", "--log-sizes", "2,4", "--max-window", "3", "--runs", "1", "--u32-only", "--profile", profile_path.to_str().unwrap()]);
+    let stdout = String::from_utf8(tune.stdout).unwrap();
+
+    assert!(tune.status.success(), "Tune should succeed: {}", String::from_utf8_lossy(&tune.stderr));
+    assert_eq!(stdout.lines().filter(|line| line.contains("points, u32 scalars: ")).count(), 2, "Tune should report one winner per size: {}", stdout);
+
+    let verify = run_cli(&["verify", "--algorithm", "auto", "--points", "20", "--seed", "5", "--tuning-profile", profile_path.to_str().unwrap()]);
+    std::fs::remove_file(&profile_path).unwrap();
+    assert!(verify.status.success(), "Verify with the profile should succeed: {}", String::from_utf8_lossy(&verify.stdout));
+
+    let missing = run_cli(&["run", "--points", "1", "--tuning-profile", profile_path.to_str().unwrap()]);
+    assert!(!missing.status.success(), "A missing profile should fail");
+}
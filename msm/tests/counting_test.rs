@@ -0,0 +1,208 @@
+use msm::algorithm::{get_algorithm, MsmConfig};
+use msm::counting::{count_operations, Operation, OperationCounts};
+use msm::naive::naive_msm;
+use msm::trivial::trivial_msm;
+use msm::pippenger::pippenger;
+use msm::sid_pippenger::sid_pippenger;
+use msm::subsum_pippenger::subsum_pippenger;
+use msm::affine_pippenger::affine_pippenger;
+use msm::thread_pool::install;
+use ark_mnt4_298::{G1Affine, G1Projective};
+use ark_ec::CurveGroup;
+use ark_std::{test_rng, UniformRand};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+#[test]
+// Naive MSM multiplies every point once and adds every product to the running result
+fn test_naive_counts() {
+    let points = generate_points(10);
+    let scalars: Vec<u32> = (1..=10).collect();
+    let (_, counts) = count_operations(|| naive_msm(&points, &scalars));
+
+    assert_eq!(counts, OperationCounts { additions: 10, scalar_multiplications: 10, ..OperationCounts::default() }, "Naive MSM counts are wrong");
+}
+
+#[test]
+// Double-and-add doubles once per scalar bit and adds once per set bit, plus once per point into the result
+fn test_trivial_counts() {
+    let points = generate_points(10);
+    let scalars = vec![0b1011u32; 10];
+    let (_, counts) = count_operations(|| trivial_msm(&points, &scalars));
+
+    assert_eq!(counts.doublings, 40, "Every point should be doubled once per scalar bit");
+    assert_eq!(counts.additions, 40, "Every point should add its three set bits and its contribution");
+}
+
+#[test]
+// With every scalar equal to 1 only window 0 has an occupied bucket
+// Each of the 8 windows of 4 bits runs 15 running sum additions and is combined with 4 doublings and 1 addition
+fn test_pippenger_counts_for_unit_scalars() {
+    let num_points = 10;
+    let points = generate_points(num_points);
+    let scalars = vec![1u32; num_points];
+    let (_, counts) = count_operations(|| pippenger(&points, &scalars, 4));
+
+    let expected_additions = num_points as u64 + 1 + 8 * 15 + 8;
+    assert_eq!(counts, OperationCounts { additions: expected_additions, doublings: 32, ..OperationCounts::default() }, "Pippenger counts are wrong");
+}
+
+#[test]
+// Per window Pippenger adds each point with a non-zero window once, each occupied bucket once into the running total
+// and runs 2^c - 1 running sum additions, then combining costs c doublings and 1 addition per window
+fn test_pippenger_counts_match_theory() {
+    let num_points = 50;
+    let window_size = 5;
+    let points = generate_points(num_points);
+    let scalars: Vec<u32> = (0..num_points as u32).map(|i| i.wrapping_mul(2654435761)).collect();
+    let (_, counts) = count_operations(|| pippenger(&points, &scalars, window_size));
+
+    let num_windows = 32usize.div_ceil(window_size);
+    let mut expected_additions = num_windows as u64;
+    for window in 0..num_windows {
+        let values: Vec<u32> = scalars.iter().map(|&scalar| (scalar >> (window * window_size)) & ((1 << window_size) - 1)).collect();
+        let non_zero = values.iter().filter(|&&value| value != 0).count() as u64;
+        let occupied = values.iter().filter(|&&value| value != 0).collect::<HashSet<_>>().len() as u64;
+        expected_additions += non_zero + occupied + (1 << window_size) - 1;
+    }
+
+    assert_eq!(counts.additions, expected_additions, "Pippenger additions do not match the theoretical count");
+    assert_eq!(counts.doublings, (num_windows * window_size) as u64, "Pippenger doublings do not match the theoretical count");
+}
+
+#[test]
+// A scalar of 15 with 4-bit windows decomposes into -1 in window 0 and a carry of 1 in window 1
+// Every point is negated once and added to bucket 1 of both windows, each window runs 2^(c-1) running sum additions
+fn test_sid_pippenger_counts() {
+    let num_points = 10;
+    let points = generate_points(num_points);
+    let scalars = vec![15u32; num_points];
+    let (_, counts) = count_operations(|| sid_pippenger(&points, &scalars, 4));
+
+    let expected_additions = 2 * (num_points as u64 + 1) + 8 * 8 + 8;
+    assert_eq!(counts, OperationCounts { additions: expected_additions, doublings: 32, negations: num_points as u64, ..OperationCounts::default() }, "SID Pippenger counts are wrong");
+}
+
+#[test]
+// Subsum accumulation only walks the occupied buckets plus bucket 0, so unit scalars cost n + 5 additions in window 0
+// and 3 additions in each empty window, against 15 running sum additions per window for Pippenger
+fn test_subsum_pippenger_counts() {
+    let num_points = 10;
+    let points = generate_points(num_points);
+    let scalars = vec![1u32; num_points];
+    let (_, counts) = count_operations(|| subsum_pippenger(&points, &scalars, 4));
+
+    let expected_additions = num_points as u64 + 5 + 7 * 3 + 8;
+    assert_eq!(counts, OperationCounts { additions: expected_additions, doublings: 32, ..OperationCounts::default() }, "Subsum Pippenger counts are wrong");
+}
+
+#[test]
+// Affine points are added to their buckets with mixed additions, everything else is unchanged from Pippenger
+fn test_affine_pippenger_counts() {
+    let num_points = 10;
+    let points: Vec<G1Affine> = G1Projective::normalize_batch(&generate_points(num_points));
+    let scalars = vec![1u32; num_points];
+    let (_, counts) = count_operations(|| affine_pippenger(&points, &scalars, 4));
+
+    let expected = OperationCounts { additions: 1 + 8 * 15 + 8, doublings: 32, mixed_additions: num_points as u64, ..OperationCounts::default() };
+    assert_eq!(counts, expected, "Affine Pippenger counts are wrong");
+}
+
+#[test]
+// The registry reports the same counts as calling the algorithm directly
+fn test_compute_with_counts() {
+    let points = generate_points(10);
+    let scalars = vec![1u32; 10];
+    let config = MsmConfig { window_size: Some(4), ..MsmConfig::default() };
+    let (result, counts) = get_algorithm::<G1Projective>("pippenger").unwrap().compute_with_counts(&points, &scalars, &config);
+    let (expected_result, expected_counts) = count_operations(|| pippenger(&points, &scalars, 4));
+
+    assert_eq!(result, expected_result, "Counting should not change the result");
+    assert_eq!(counts, expected_counts, "Registry counts differ from the direct call");
+}
+
+#[test]
+// Work without group operations counts nothing, and the report sums and reads each kind
+fn test_report() {
+    let (result, counts) = count_operations(|| 1 + 1);
+    assert_eq!(result, 2, "count_operations should return the result of its closure");
+    assert_eq!(counts, OperationCounts::default(), "No operation should be counted");
+
+    let counts = OperationCounts { additions: 1, doublings: 2, negations: 3, mixed_additions: 4, scalar_multiplications: 5 };
+    assert_eq!(counts.total(), 15, "Total should sum every kind");
+    assert_eq!(counts.get(Operation::Negation), 3, "get should read the matching kind");
+    assert_eq!(counts.to_string(), "additions: 1, doublings: 2, negations: 3, mixed additions: 4, scalar multiplications: 5", "Report is formatted wrongly");
+}
+
+#[test]
+// An inner count should report its own operations and the outer count should include them, rather than wait for each other
+fn test_nested_counts() {
+    let points = generate_points(10);
+    let scalars: Vec<u32> = (1..=10).collect();
+    let ((_, inner), outer) = count_operations(|| {
+        let _ = trivial_msm(&points, &scalars);
+        count_operations(|| naive_msm(&points, &scalars))
+    });
+    let (_, trivial) = count_operations(|| trivial_msm(&points, &scalars));
+
+    assert_eq!(inner, OperationCounts { additions: 10, scalar_multiplications: 10, ..OperationCounts::default() }, "Inner count is wrong");
+    assert_eq!(outer.additions, inner.additions + trivial.additions, "The outer count should include the inner one");
+    assert_eq!(outer.scalar_multiplications, 10, "The outer count should include the inner one");
+}
+
+#[test]
+// Operations of an uncounted MSM on another thread should not be reported by a concurrent count
+fn test_count_ignores_other_threads() {
+    let points = generate_points(10);
+    let scalars: Vec<u32> = (1..=10).collect();
+    let done = AtomicBool::new(false);
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            while !done.load(Ordering::SeqCst) {
+                let _ = naive_msm(&points, &scalars);
+            }
+        });
+        for _ in 0..20 {
+            let (_, counts) = count_operations(|| pippenger(&points, &scalars, 4));
+            assert_eq!(counts.scalar_multiplications, 0, "Naive MSM on another thread should not be counted");
+        }
+        done.store(true, Ordering::SeqCst);
+    });
+}
+
+#[test]
+// Parallel variants given a thread count should still report the operations run on that pool
+fn test_count_with_thread_count() {
+    let points = generate_points(40);
+    let scalars: Vec<u32> = (1..=40).collect();
+    let algorithm = get_algorithm::<G1Projective>("parallel_pippenger").unwrap();
+    let (_, expected_counts) = algorithm.compute_with_counts(&points, &scalars, &MsmConfig { window_size: Some(4), ..MsmConfig::default() });
+    let (_, counts) = algorithm.compute_with_counts(&points, &scalars, &MsmConfig { window_size: Some(4), num_threads: Some(2) });
+
+    assert!(counts.additions > 0, "Additions on the pool should be counted");
+    assert_eq!(counts, expected_counts, "The thread count should not change the counts");
+}
+
+#[test]
+// Consecutive counts should reuse the same cached workers, and each should only report its own operations
+fn test_counts_reuse_pool() {
+    let points = generate_points(10);
+    let scalars: Vec<u32> = (1..=10).collect();
+    // No other test in this file uses five threads, so the pool of five workers is not leased to anything else
+    let counted_workers = || install(Some(5), || count_operations(|| {
+        let _ = naive_msm(&points, &scalars);
+        rayon::broadcast(|_| std::thread::current().id()).into_iter().collect::<HashSet<_>>()
+    }));
+
+    let (first_workers, first_counts) = counted_workers();
+    let (second_workers, second_counts) = counted_workers();
+    assert_eq!(first_workers, second_workers, "The second count should run on the workers of the first");
+    assert_eq!(first_counts, second_counts, "The second count should not include the operations of the first");
+}
@@ -0,0 +1,106 @@
+use msm::naive::naive_msm;
+use msm::trivial::trivial_msm;
+use msm::bos_coster::bos_coster;
+use msm::pippenger::pippenger;
+use msm::parallel_pippenger::parallel_pippenger;
+use msm::subsum_pippenger::subsum_pippenger;
+use msm::sid_pippenger::sid_pippenger;
+use msm::parallel_sid_pippenger::parallel_sid_pippenger;
+use msm::parallel_subsum_pippenger::parallel_subsum_pippenger;
+use msm::sid_subsum_pippenger::sid_subsum_pippenger;
+use msm::parallel_sid_subsum_pippenger::parallel_sid_subsum_pippenger;
+use msm::chunked_pippenger::chunked_pippenger;
+use msm::hybrid_pippenger::hybrid_pippenger;
+use msm::batch_affine::SwCurveGroup;
+use msm::batch_affine_pippenger::batch_affine_pippenger;
+use msm::sid_batch_affine_pippenger::sid_batch_affine_pippenger;
+use msm::affine_pippenger::affine_pippenger;
+use msm::flat_pippenger::flat_pippenger;
+use msm::flat_sid_pippenger::flat_sid_pippenger;
+use msm::straus::straus;
+use msm::wnaf_msm::wnaf_msm;
+use msm::booth::booth_pippenger;
+use ark_std::test_rng;
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points on any curve group
+fn generate_points<G: SwCurveGroup>(num_points: usize) -> Vec<G> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars covering the full u32 range
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Runs every algorithm on the same inputs and compares against naive MSM
+fn check_all_algorithms<G: SwCurveGroup>(num_points: usize, window_size: usize) {
+    let points: Vec<G> = generate_points(num_points);
+    let scalars = generate_scalars(num_points);
+    let expected_result = naive_msm(&points, &scalars);
+
+    assert_eq!(trivial_msm(&points, &scalars), expected_result, "Trivial MSM did not match naive MSM");
+    assert_eq!(bos_coster(&points, &scalars), expected_result, "Bos-Coster did not match naive MSM");
+    assert_eq!(pippenger(&points, &scalars, window_size), expected_result, "Pippenger did not match naive MSM");
+    assert_eq!(parallel_pippenger(&points, &scalars, window_size), expected_result, "Parallel Pippenger did not match naive MSM");
+    assert_eq!(subsum_pippenger(&points, &scalars, window_size), expected_result, "Subsum Pippenger did not match naive MSM");
+    assert_eq!(sid_pippenger(&points, &scalars, window_size), expected_result, "SID Pippenger did not match naive MSM");
+    assert_eq!(parallel_sid_pippenger(&points, &scalars, window_size), expected_result, "Parallel SID Pippenger did not match naive MSM");
+    assert_eq!(parallel_subsum_pippenger(&points, &scalars, window_size), expected_result, "Parallel Subsum Pippenger did not match naive MSM");
+    assert_eq!(sid_subsum_pippenger(&points, &scalars, window_size), expected_result, "SID Subsum Pippenger did not match naive MSM");
+    assert_eq!(parallel_sid_subsum_pippenger(&points, &scalars, window_size), expected_result, "Parallel SID Subsum Pippenger did not match naive MSM");
+    assert_eq!(chunked_pippenger(&points, &scalars, window_size), expected_result, "Chunked Pippenger did not match naive MSM");
+    assert_eq!(hybrid_pippenger(&points, &scalars, window_size), expected_result, "Hybrid Pippenger did not match naive MSM");
+    assert_eq!(batch_affine_pippenger(&points, &scalars, window_size), expected_result, "Batch Affine Pippenger did not match naive MSM");
+    assert_eq!(sid_batch_affine_pippenger(&points, &scalars, window_size), expected_result, "SID Batch Affine Pippenger did not match naive MSM");
+    assert_eq!(flat_pippenger(&points, &scalars, window_size), expected_result, "Flat Pippenger did not match naive MSM");
+    assert_eq!(flat_sid_pippenger(&points, &scalars, window_size), expected_result, "Flat SID Pippenger did not match naive MSM");
+    assert_eq!(straus(&points, &scalars, window_size), expected_result, "Straus did not match naive MSM");
+    assert_eq!(wnaf_msm(&points, &scalars, window_size), expected_result, "wNAF MSM did not match naive MSM");
+    assert_eq!(booth_pippenger(&points, &scalars, window_size), expected_result, "Booth Pippenger did not match naive MSM");
+
+    let affine_points = G::normalize_batch(&points);
+    assert_eq!(affine_pippenger(&affine_points, &scalars, window_size), expected_result, "Affine Pippenger did not match naive MSM");
+}
+
+#[test]
+fn test_mnt4_298_g1() {
+    check_all_algorithms::<ark_mnt4_298::G1Projective>(20, 4);
+}
+
+#[test]
+fn test_mnt4_298_g2() {
+    check_all_algorithms::<ark_mnt4_298::G2Projective>(10, 3);
+}
+
+#[test]
+fn test_mnt4_753_g1() {
+    check_all_algorithms::<ark_mnt4_753::G1Projective>(10, 4);
+}
+
+#[test]
+fn test_mnt4_753_g2() {
+    check_all_algorithms::<ark_mnt4_753::G2Projective>(5, 3);
+}
+
+#[test]
+fn test_mnt6_298_g1() {
+    check_all_algorithms::<ark_mnt6_298::G1Projective>(20, 4);
+}
+
+#[test]
+fn test_mnt6_298_g2() {
+    check_all_algorithms::<ark_mnt6_298::G2Projective>(10, 3);
+}
+
+#[test]
+fn test_mnt6_753_g1() {
+    check_all_algorithms::<ark_mnt6_753::G1Projective>(10, 4);
+}
+
+#[test]
+fn test_mnt6_753_g2() {
+    check_all_algorithms::<ark_mnt6_753::G2Projective>(5, 3);
+}
@@ -0,0 +1,141 @@
+use msm::error::{check_lengths, check_scalar_width, check_window_size, check_window_size_range, MsmError, MAX_FLAT_WINDOW_SIZE, MAX_WINDOW_SIZE, MIN_SID_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use msm::naive::{naive_msm, try_naive_msm, try_naive_msm_field};
+use msm::trivial::try_trivial_msm;
+use msm::pippenger::{try_pippenger, try_pippenger_field};
+use msm::parallel_pippenger::try_parallel_pippenger;
+use msm::subsum_pippenger::try_subsum_pippenger;
+use msm::sid_pippenger::{try_sid_pippenger, try_sid_pippenger_field};
+use msm::parallel_sid_pippenger::try_parallel_sid_pippenger;
+use msm::parallel_subsum_pippenger::try_parallel_subsum_pippenger;
+use msm::sid_subsum_pippenger::try_sid_subsum_pippenger;
+use msm::parallel_sid_subsum_pippenger::try_parallel_sid_subsum_pippenger;
+use msm::flat_pippenger::{try_flat_pippenger, try_flat_pippenger_field};
+use msm::flat_sid_pippenger::{try_flat_sid_pippenger, try_flat_sid_pippenger_field};
+use msm::batch_affine_pippenger::{try_batch_affine_pippenger, try_batch_affine_pippenger_field};
+use msm::sid_batch_affine_pippenger::{try_sid_batch_affine_pippenger, try_sid_batch_affine_pippenger_field};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_std::{test_rng, UniformRand};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+#[test]
+fn test_check_lengths() {
+    assert_eq!(check_lengths(3, 3), Ok(()), "Equal lengths should be accepted");
+    assert_eq!(check_lengths(1, 2), Err(MsmError::LengthMismatch { points: 1, scalars: 2 }), "Different lengths should be rejected");
+}
+
+#[test]
+fn test_check_window_size() {
+    assert_eq!(check_window_size(MIN_WINDOW_SIZE, MIN_WINDOW_SIZE), Ok(()), "Smallest window should be accepted");
+    assert_eq!(check_window_size(MAX_WINDOW_SIZE, MIN_WINDOW_SIZE), Ok(()), "Largest window should be accepted");
+    assert_eq!(check_window_size(0, MIN_WINDOW_SIZE), Err(MsmError::InvalidWindowSize { window_size: 0, min: MIN_WINDOW_SIZE, max: MAX_WINDOW_SIZE }), "Window size 0 should be rejected");
+    assert_eq!(check_window_size(32, MIN_WINDOW_SIZE), Err(MsmError::InvalidWindowSize { window_size: 32, min: MIN_WINDOW_SIZE, max: MAX_WINDOW_SIZE }), "Window size 32 should be rejected");
+    assert_eq!(check_window_size(1, MIN_SID_WINDOW_SIZE), Err(MsmError::InvalidWindowSize { window_size: 1, min: MIN_SID_WINDOW_SIZE, max: MAX_WINDOW_SIZE }), "Window size 1 should be rejected for SID");
+}
+
+#[test]
+fn test_check_scalar_width() {
+    // MNT4-298 scalars are 298 bits wide
+    assert_eq!(check_scalar_width::<G1Projective>(298), Ok(()), "Scalars as wide as the field should be accepted");
+    assert_eq!(check_scalar_width::<G1Projective>(753), Err(MsmError::ScalarTooWide { scalar_bits: 753, max_bits: 298 }), "Scalars wider than the field should be rejected");
+}
+
+#[test]
+fn test_error_display() {
+    let error = MsmError::LengthMismatch { points: 1, scalars: 2 };
+    assert_eq!(error.to_string(), "Points and scalars must have the same length (1 points, 2 scalars)", "Unexpected error message");
+}
+
+#[test]
+// Every fallible entry point should report mismatched lengths instead of panicking
+fn test_try_functions_with_different_lengths() {
+    let points = generate_points(1);
+    let scalars = vec![1, 2];
+    let expected = Err(MsmError::LengthMismatch { points: 1, scalars: 2 });
+
+    assert_eq!(try_naive_msm(&points, &scalars), expected, "Naive MSM should reject different lengths");
+    assert_eq!(try_trivial_msm(&points, &scalars), expected, "Trivial MSM should reject different lengths");
+    assert_eq!(try_pippenger(&points, &scalars, 2), expected, "Pippenger should reject different lengths");
+    assert_eq!(try_parallel_pippenger(&points, &scalars, 2), expected, "Parallel Pippenger should reject different lengths");
+    assert_eq!(try_subsum_pippenger(&points, &scalars, 2), expected, "Subsum Pippenger should reject different lengths");
+    assert_eq!(try_sid_pippenger(&points, &scalars, 2), expected, "SID Pippenger should reject different lengths");
+    assert_eq!(try_parallel_sid_pippenger(&points, &scalars, 2), expected, "Parallel SID Pippenger should reject different lengths");
+    assert_eq!(try_parallel_subsum_pippenger(&points, &scalars, 2), expected, "Parallel Subsum Pippenger should reject different lengths");
+    assert_eq!(try_sid_subsum_pippenger(&points, &scalars, 2), expected, "SID Subsum Pippenger should reject different lengths");
+    assert_eq!(try_parallel_sid_subsum_pippenger(&points, &scalars, 2), expected, "Parallel SID Subsum Pippenger should reject different lengths");
+}
+
+#[test]
+// Window sizes that would overflow the shifts should be rejected
+fn test_try_pippenger_with_invalid_window_sizes() {
+    let points = generate_points(2);
+    let scalars = vec![1, 2];
+
+    assert!(matches!(try_pippenger(&points, &scalars, 0), Err(MsmError::InvalidWindowSize { .. })), "Window size 0 should be rejected");
+    assert!(matches!(try_pippenger(&points, &scalars, 32), Err(MsmError::InvalidWindowSize { .. })), "Window size 32 should be rejected");
+    assert!(matches!(try_pippenger(&points, &scalars, 40), Err(MsmError::InvalidWindowSize { .. })), "Window size 40 should be rejected");
+}
+
+#[test]
+// SID needs at least two bits per window
+fn test_try_sid_pippenger_with_window_size_one() {
+    let points = generate_points(2);
+    let scalars = vec![1, 2];
+
+    assert!(matches!(try_sid_pippenger(&points, &scalars, 1), Err(MsmError::InvalidWindowSize { min: 2, .. })), "SID with window size 1 should be rejected");
+    assert!(matches!(try_sid_pippenger_field(&points, &[Fr::from(1u64), Fr::from(2u64)], 1), Err(MsmError::InvalidWindowSize { min: 2, .. })), "SID with window size 1 should be rejected");
+    assert!(try_pippenger(&points, &scalars, 1).is_ok(), "Unsigned windows of size 1 should be accepted");
+}
+
+#[test]
+// A custom maximum should be enforced and reported in the error
+fn test_check_window_size_range() {
+    assert_eq!(check_window_size_range(8, MIN_WINDOW_SIZE, 8), Ok(()), "The custom maximum should be accepted");
+    assert_eq!(check_window_size_range(9, MIN_WINDOW_SIZE, 8), Err(MsmError::InvalidWindowSize { window_size: 9, min: MIN_WINDOW_SIZE, max: 8 }), "Windows above the custom maximum should be rejected");
+}
+
+#[test]
+// The flat variants allocate every bucket up front, so windows above MAX_FLAT_WINDOW_SIZE are rejected before allocating
+fn test_try_flat_variants_with_oversized_window() {
+    let points = generate_points(2);
+    let window_size = MAX_FLAT_WINDOW_SIZE + 1;
+    let expected = Err(MsmError::InvalidWindowSize { window_size, min: MIN_WINDOW_SIZE, max: MAX_FLAT_WINDOW_SIZE });
+    let expected_sid = Err(MsmError::InvalidWindowSize { window_size, min: MIN_SID_WINDOW_SIZE, max: MAX_FLAT_WINDOW_SIZE });
+
+    assert_eq!(try_flat_pippenger(&points, &[1, 2], window_size), expected, "Flat Pippenger should reject the window");
+    assert_eq!(try_flat_pippenger_field(&points, &[Fr::from(1u64), Fr::from(2u64)], window_size), expected, "Flat Pippenger should reject the window");
+    assert_eq!(try_flat_sid_pippenger(&points, &[1, 2], window_size), expected_sid, "Flat SID Pippenger should reject the window");
+    assert_eq!(try_flat_sid_pippenger_field(&points, &[Fr::from(1u64), Fr::from(2u64)], window_size), expected_sid, "Flat SID Pippenger should reject the window");
+}
+
+#[test]
+// The batch-affine variants also allocate every bucket up front, so they share the flat variants' cap
+fn test_try_batch_affine_variants_with_oversized_window() {
+    let points = generate_points(2);
+    let window_size = MAX_FLAT_WINDOW_SIZE + 1;
+    let expected = Err(MsmError::InvalidWindowSize { window_size, min: MIN_WINDOW_SIZE, max: MAX_FLAT_WINDOW_SIZE });
+    let expected_sid = Err(MsmError::InvalidWindowSize { window_size, min: MIN_SID_WINDOW_SIZE, max: MAX_FLAT_WINDOW_SIZE });
+
+    assert_eq!(try_batch_affine_pippenger(&points, &[1, 2], window_size), expected, "Batch Affine Pippenger should reject the window");
+    assert_eq!(try_batch_affine_pippenger_field(&points, &[Fr::from(1u64), Fr::from(2u64)], window_size), expected, "Batch Affine Pippenger should reject the window");
+    assert_eq!(try_sid_batch_affine_pippenger(&points, &[1, 2], window_size), expected_sid, "SID Batch Affine Pippenger should reject the window");
+    assert_eq!(try_sid_batch_affine_pippenger_field(&points, &[Fr::from(1u64), Fr::from(2u64)], window_size), expected_sid, "SID Batch Affine Pippenger should reject the window");
+}
+
+#[test]
+// Valid inputs should compute the same result as the panicking entry points
+fn test_try_functions_with_valid_inputs() {
+    let mut rng = test_rng();
+    let points = generate_points(10);
+    let scalars: Vec<u32> = (0..10).map(|i| 1000 * i + 7).collect();
+    let field_scalars: Vec<Fr> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+    let expected_result = naive_msm(&points, &scalars);
+
+    assert_eq!(try_pippenger(&points, &scalars, 3), Ok(expected_result), "Pippenger should succeed with valid inputs");
+    assert_eq!(try_sid_pippenger(&points, &scalars, 3), Ok(expected_result), "SID Pippenger should succeed with valid inputs");
+    assert_eq!(try_pippenger_field(&points, &field_scalars, 4), try_naive_msm_field(&points, &field_scalars), "Pippenger should succeed with valid field inputs");
+}
@@ -0,0 +1,146 @@
+use msm::fixed_base::FixedBaseMsmContext;
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::error::{MsmError, MAX_FLAT_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ec::Group;
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Tables should hold one multiple per base and window
+fn test_context_dimensions() {
+    let points = generate_points(10);
+    let context = FixedBaseMsmContext::with_scalar_bits(&points, 5, 32);
+
+    assert_eq!(context.window_size(), 5, "Incorrect window size");
+    assert_eq!(context.scalar_bits(), 32, "Incorrect scalar bits");
+    // ceil(32/5) = 7 windows
+    assert_eq!(context.num_windows(), 7, "Incorrect number of windows");
+    assert_eq!(context.num_points(), 10, "Incorrect number of points");
+    assert_eq!(context.table_size(), 70, "Tables should hold num_points * num_windows points");
+}
+
+#[test]
+// A scalar with a single bit set in each window checks that each table entry is the right power of two multiple
+fn test_fixed_base_msm_powers_of_two() {
+    let points = generate_points(1);
+    let context = FixedBaseMsmContext::with_scalar_bits(&points, 4, 32);
+
+    for bit in 0..32 {
+        let scalars = vec![1u32 << bit];
+        let mut expected_result = points[0];
+        for _ in 0..bit {
+            expected_result = expected_result.double();
+        }
+        assert_eq!(context.msm(&scalars), expected_result, "Incorrect result for 2^{}", bit);
+    }
+}
+
+#[test]
+fn test_fixed_base_msm_with_zero_scalars() {
+    let points = generate_points(3);
+    let context = FixedBaseMsmContext::with_scalar_bits(&points, 4, 32);
+    assert_eq!(context.msm(&[0, 0, 0]), G1Projective::zero(), "Fixed-base MSM with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_fixed_base_msm_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let context = FixedBaseMsmContext::new(&points, 4);
+    assert_eq!(context.msm(&[]), G1Projective::zero(), "Fixed-base MSM with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_fixed_base_msm_with_different_lengths() {
+    let points = generate_points(1);
+    let context = FixedBaseMsmContext::with_scalar_bits(&points, 4, 32);
+    let panic_result = context.msm(&[1, 2]); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+// The same context should answer many MSMs over the same bases
+fn test_fixed_base_msm_reused() {
+    let points = generate_points(100);
+    let context = FixedBaseMsmContext::with_scalar_bits(&points, 8, 32);
+
+    for _ in 0..5 {
+        let scalars = generate_scalars(100);
+        assert_eq!(context.msm(&scalars), naive_msm(&points, &scalars), "Fixed-base MSM did not match naive MSM");
+    }
+}
+
+#[test]
+// Window sizes that do not divide the scalar width leave a narrower top window
+fn test_fixed_base_msm_uneven_windows() {
+    let points = generate_points(50);
+    let scalars = generate_scalars(50);
+    let expected_result = naive_msm(&points, &scalars);
+
+    for window_size in [1, 3, 7, 13] {
+        let context = FixedBaseMsmContext::with_scalar_bits(&points, window_size, 32);
+        assert_eq!(context.msm(&scalars), expected_result, "Fixed-base MSM with window size {} did not match naive MSM", window_size);
+    }
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_fixed_base_msm_field() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let context = FixedBaseMsmContext::new(&points, 6);
+
+    assert_eq!(context.msm_field(&scalars), naive_msm_field(&points, &scalars), "Fixed-base MSM with field scalars did not match naive MSM");
+    // Full-width tables also cover u32 scalars
+    let small_scalars = generate_scalars(100);
+    assert_eq!(context.msm(&small_scalars), naive_msm(&points, &small_scalars), "Full-width context did not handle u32 scalars");
+}
+
+#[test]
+fn test_try_fixed_base_msm() {
+    let points = generate_points(2);
+    let narrow_context = FixedBaseMsmContext::with_scalar_bits(&points, 4, 16);
+
+    assert_eq!(narrow_context.try_msm(&[1]), Err(MsmError::LengthMismatch { points: 2, scalars: 1 }), "Mismatched lengths should be rejected");
+    assert_eq!(narrow_context.try_msm(&[1, 2]), Err(MsmError::ScalarTooWide { scalar_bits: 32, max_bits: 16 }), "Tables narrower than the scalars should be rejected");
+    assert_eq!(narrow_context.try_msm_field(&[Fr::from(1u64), Fr::from(2u64)]), Err(MsmError::ScalarTooWide { scalar_bits: 298, max_bits: 16 }), "Tables narrower than the field should be rejected");
+    assert!(FixedBaseMsmContext::try_with_scalar_bits(&points, 0, 32).is_err(), "Zero window size should be rejected");
+    assert!(FixedBaseMsmContext::try_with_scalar_bits(&points, 32, 32).is_err(), "Window size above 31 should be rejected");
+}
+
+#[test]
+// Every MSM allocates all 2^window_size - 1 buckets, so windows above the flat cap are rejected instead of aborting on allocation
+fn test_try_fixed_base_with_oversized_window() {
+    let points = generate_points(2);
+    let window_size = MAX_FLAT_WINDOW_SIZE + 1;
+
+    assert_eq!(FixedBaseMsmContext::try_with_scalar_bits(&points, window_size, 32).err(), Some(MsmError::InvalidWindowSize { window_size, min: MIN_WINDOW_SIZE, max: MAX_FLAT_WINDOW_SIZE }), "Window size above the cap should be rejected");
+    assert!(FixedBaseMsmContext::try_with_scalar_bits(&points, 30, 32).is_err(), "Window size 30 should be rejected");
+}
+
+#[test]
+#[should_panic(expected = "Window size 25 is outside the supported range 1..=24")]
+fn test_fixed_base_with_oversized_window() {
+    let points = generate_points(2);
+    FixedBaseMsmContext::with_scalar_bits(&points, MAX_FLAT_WINDOW_SIZE + 1, 32);
+}
@@ -0,0 +1,119 @@
+use msm::flat_pippenger::{flat_pippenger, flat_pippenger_field, flat_compute_msm_for_partition, flat_combine_partitioned_msm};
+use msm::pippenger::{pippenger, partition_msm, MsmPartition};
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::operations::add_points;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+#[test]
+fn test_flat_pippenger_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];
+    let window_size = 2;
+    assert_eq!(flat_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Flat Pippenger with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_flat_pippenger_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 5;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(flat_pippenger(&points, &scalars, window_size), expected_result, "Flat Pippenger with maximum scalars failed");
+}
+
+#[test]
+fn test_flat_pippenger_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 2;
+    assert_eq!(flat_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Flat Pippenger with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_flat_pippenger_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = flat_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Test for Step 2: Compute MSM for each partition with a flat bucket array
+fn test_flat_compute_msm_for_partition() {
+    let points = generate_points(10);
+    let partition = MsmPartition { bit_index: 0, window_values: vec![1, 0, 1, 0, 1, 0, 1, 0, 1, 0] };
+    let window_size = 2;
+
+    let msm_result = flat_compute_msm_for_partition(&partition, &points, window_size);
+    // Compare against result by adding points
+    let expected_result = points.iter().step_by(2).fold(G1Projective::zero(), |acc, &p| add_points(acc, p));
+    assert_eq!(msm_result, expected_result, "MSM computation for partition failed");
+}
+
+#[test]
+// Test for Step 3: Compute the final MSM result by combining all partitions
+fn test_flat_combine_msm() {
+    let points = generate_points(10);
+    let scalars = generate_scalars(10);
+    let window_size = 3;
+
+    let partitions = partition_msm(&scalars, window_size);
+    let combined_result = flat_combine_partitioned_msm(&partitions, &points, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(combined_result, expected_result, "Combined MSM result is incorrect");
+}
+
+#[test]
+// "Comprehensive test with 1000 points", the flat buckets should agree with the map-based buckets
+fn test_flat_pippenger_algorithm() {
+    let points = generate_points(1000);
+    let scalars = generate_scalars(1000);
+    let window_size = 8;
+
+    let msm_result = flat_pippenger(&points, &scalars, window_size);
+    assert_eq!(msm_result, pippenger(&points, &scalars, window_size), "Flat Pippenger did not match map-based Pippenger");
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Flat Pippenger did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_flat_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+
+    let msm_result = flat_pippenger_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Flat Pippenger with field scalars did not match expected result");
+}
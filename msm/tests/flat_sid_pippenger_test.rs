@@ -0,0 +1,97 @@
+use msm::flat_sid_pippenger::{flat_sid_pippenger, flat_sid_pippenger_field, flat_sid_compute_msm_for_partition};
+use msm::sid_pippenger::{sid_pippenger, SidMsmPartitionDecomposed};
+use msm::naive::{naive_msm, naive_msm_field};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ec::Group;
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+#[test]
+fn test_flat_sid_pippenger_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];
+    let window_size = 2;
+    assert_eq!(flat_sid_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Flat SID Pippenger with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_flat_sid_pippenger_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 4;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(flat_sid_pippenger(&points, &scalars, window_size), expected_result, "Flat SID Pippenger with maximum scalars failed");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_flat_sid_pippenger_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = flat_sid_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Test for Step 2: The most negative window value -2^(c-1) should land in the last bucket
+fn test_flat_sid_compute_msm_for_partition() {
+    let points = generate_points(4);
+    let partition = SidMsmPartitionDecomposed { bit_index: 0, window_values: vec![2, -2, 1, -1] };
+    let window_size = 2;
+
+    let msm_result = flat_sid_compute_msm_for_partition(&partition, &points, window_size);
+    // Compare against 2P0 - 2P1 + P2 - P3
+    let expected_result = points[0].double() - points[1].double() + points[2] - points[3];
+    assert_eq!(msm_result, expected_result, "MSM computation for partition failed");
+}
+
+#[test]
+// "Comprehensive test with 1000 points", the flat buckets should agree with the map-based buckets
+fn test_flat_sid_pippenger_algorithm() {
+    let points = generate_points(1000);
+    let scalars = generate_scalars(1000);
+    let window_size = 8;
+
+    let msm_result = flat_sid_pippenger(&points, &scalars, window_size);
+    assert_eq!(msm_result, sid_pippenger(&points, &scalars, window_size), "Flat SID Pippenger did not match map-based SID Pippenger");
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Flat SID Pippenger did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_flat_sid_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+
+    let msm_result = flat_sid_pippenger_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Flat SID Pippenger with field scalars did not match expected result");
+}
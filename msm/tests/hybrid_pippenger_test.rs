@@ -0,0 +1,99 @@
+use msm::hybrid_pippenger::{hybrid_pippenger, hybrid_pippenger_with_chunks, hybrid_pippenger_field, hybrid_pippenger_field_with_chunks, hybrid_combine_chunked_msm, HybridMsmChunk};
+use msm::parallel_pippenger::parallel_partition_msm;
+use msm::naive::{naive_msm, naive_msm_field};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+fn test_hybrid_pippenger_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 2;
+    assert_eq!(hybrid_pippenger(&points, &scalars, window_size), G1Projective::zero(), "Hybrid Pippenger with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_hybrid_pippenger_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = hybrid_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+// Combining two chunks should give the MSM over their concatenation
+fn test_hybrid_combine_chunked_msm() {
+    let points = generate_points(20);
+    let scalars = generate_scalars(20);
+    let window_size = 3;
+
+    let chunks = vec![
+        HybridMsmChunk { points: &points[..8], partitions: parallel_partition_msm(&scalars[..8], window_size) },
+        HybridMsmChunk { points: &points[8..], partitions: parallel_partition_msm(&scalars[8..], window_size) },
+    ];
+    let combined_result = hybrid_combine_chunked_msm(&chunks, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(combined_result, expected_result, "Combined MSM result is incorrect");
+}
+
+#[test]
+// The number of chunks should not change the result, including uneven splits and more chunks than points
+fn test_hybrid_pippenger_with_chunks() {
+    let points = generate_points(100);
+    let scalars = generate_scalars(100);
+    let window_size = 4;
+    let expected_result = naive_msm(&points, &scalars);
+
+    for num_chunks in [1, 3, 7, 100, 150] {
+        assert_eq!(hybrid_pippenger_with_chunks(&points, &scalars, window_size, num_chunks), expected_result, "Hybrid Pippenger with {} chunks did not match expected result", num_chunks);
+    }
+}
+
+#[test]
+// "Comprehensive test with 1000 points", window size 16 leaves only 2 windows so the chunks supply the rest of the parallelism
+fn test_hybrid_pippenger_algorithm() {
+    let points = generate_points(1000);
+    let scalars = generate_scalars(1000);
+    let window_size = 16;
+
+    let msm_result = hybrid_pippenger(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Hybrid Pippenger did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_hybrid_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+    let expected_result = naive_msm_field(&points, &scalars);
+
+    assert_eq!(hybrid_pippenger_field(&points, &scalars, window_size), expected_result, "Hybrid Pippenger with field scalars did not match expected result");
+    assert_eq!(hybrid_pippenger_field_with_chunks(&points, &scalars, window_size, 3), expected_result, "Hybrid Pippenger with field scalars and 3 chunks did not match expected result");
+}
@@ -0,0 +1,143 @@
+use msm::io::{from_hex, read_binary, read_binary_file, read_job_binary, read_job_json, read_result_json, read_scalars_csv, read_scalars_csv_file, to_hex, write_binary, write_binary_file, write_job_binary, write_job_json, write_result_json, write_scalars_csv, write_scalars_csv_file, MsmJob};
+use msm::out_of_core::{out_of_core_msm_field, OutOfCoreConfig};
+use msm::naive::naive_msm_field;
+use msm::error::MsmIoError;
+use ark_mnt4_298::{G1Affine, G1Projective, Fr};
+use ark_ec::CurveGroup;
+use ark_serialize::{Compress, Validate};
+use ark_std::{test_rng, UniformRand};
+
+// Helper function to generate a job of n points and full-width field scalars
+fn generate_job(num_points: usize) -> MsmJob<G1Affine> {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars = (0..num_points).map(|_| Fr::rand(&mut rng)).collect();
+    MsmJob { points: G1Projective::normalize_batch(&points), scalars }
+}
+
+#[test]
+// Binary values should round trip in both compressed and uncompressed form
+fn test_binary_round_trip() {
+    let job = generate_job(10);
+
+    for compress in [Compress::Yes, Compress::No] {
+        let mut bytes = Vec::new();
+        write_binary(&job.points, &mut bytes, compress).unwrap();
+        let points: Vec<G1Affine> = read_binary(&bytes[..], compress, Validate::Yes).unwrap();
+        assert_eq!(points, job.points, "Points did not round trip");
+    }
+}
+
+#[test]
+// Compressed points take roughly half the space of uncompressed points
+fn test_binary_compression() {
+    let job = generate_job(10);
+    let mut compressed = Vec::new();
+    let mut uncompressed = Vec::new();
+    write_binary(&job.points, &mut compressed, Compress::Yes).unwrap();
+    write_binary(&job.points, &mut uncompressed, Compress::No).unwrap();
+    assert!(compressed.len() < uncompressed.len(), "Compressed points should be smaller");
+}
+
+#[test]
+// Binary points files can be read by the out-of-core MSM
+fn test_binary_points_for_out_of_core_msm() {
+    let job = generate_job(20);
+    let mut bytes = Vec::new();
+    write_binary(&job.points[..], &mut bytes, Compress::No).unwrap();
+
+    let result = out_of_core_msm_field::<G1Affine, _>(&bytes[..], &job.scalars, &OutOfCoreConfig::default()).unwrap();
+    let points: Vec<G1Projective> = job.points.iter().map(|&point| point.into()).collect();
+    assert_eq!(result, naive_msm_field(&points, &job.scalars), "Out-of-core MSM over a binary points file did not match naive MSM");
+}
+
+#[test]
+fn test_binary_job_round_trip() {
+    let job = generate_job(8);
+    let mut bytes = Vec::new();
+    write_job_binary(&job, &mut bytes, Compress::Yes).unwrap();
+
+    let read_job: MsmJob<G1Affine> = read_job_binary(&bytes[..], Compress::Yes, Validate::Yes).unwrap();
+    assert_eq!(read_job.points, job.points, "Job points did not round trip");
+    assert_eq!(read_job.scalars, job.scalars, "Job scalars did not round trip");
+}
+
+#[test]
+fn test_binary_file_round_trip() {
+    let job = generate_job(5);
+    let path = std::env::temp_dir().join(format!("msm_io_test_{}.bin", std::process::id()));
+
+    write_binary_file(&job.scalars, &path, Compress::No).unwrap();
+    let scalars: Result<Vec<Fr>, _> = read_binary_file(&path, Compress::No, Validate::Yes);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(scalars.unwrap(), job.scalars, "Scalars did not round trip through a file");
+}
+
+#[test]
+fn test_hex_round_trip() {
+    let job = generate_job(1);
+    let hex_string = to_hex(&job.points[0]).unwrap();
+
+    assert_eq!(from_hex::<G1Affine>(&hex_string).unwrap(), job.points[0], "Point did not round trip through hex");
+    assert_eq!(from_hex::<G1Affine>(&format!("0x{}", hex_string)).unwrap(), job.points[0], "A 0x prefix should be accepted");
+    assert!(matches!(from_hex::<G1Affine>("zz"), Err(MsmIoError::Format(_))), "Invalid hex should be rejected");
+}
+
+#[test]
+fn test_json_job_round_trip() {
+    let job = generate_job(6);
+    let mut bytes = Vec::new();
+    write_job_json(&job, &mut bytes).unwrap();
+
+    let read_job: MsmJob<G1Affine> = read_job_json(&bytes[..]).unwrap();
+    assert_eq!(read_job.points, job.points, "Job points did not round trip through JSON");
+    assert_eq!(read_job.scalars, job.scalars, "Job scalars did not round trip through JSON");
+}
+
+#[test]
+fn test_json_job_with_missing_field() {
+    let result = read_job_json::<G1Affine, _>(&br#"{"points": []}"#[..]);
+    assert!(matches!(result, Err(MsmIoError::Format(_))), "A job without scalars should be rejected");
+}
+
+#[test]
+fn test_json_result_round_trip() {
+    let job = generate_job(4);
+    let points: Vec<G1Projective> = job.points.iter().map(|&point| point.into()).collect();
+    let result = naive_msm_field(&points, &job.scalars);
+
+    let mut bytes = Vec::new();
+    write_result_json(&result, &mut bytes).unwrap();
+    assert_eq!(read_result_json::<G1Projective, _>(&bytes[..]).unwrap(), result, "Result did not round trip through JSON");
+}
+
+#[test]
+// Scalars should be written as decimals and read back from commas or newlines
+fn test_csv_scalars() {
+    let scalars = vec![0u32, 1, 42, u32::MAX];
+    let mut bytes = Vec::new();
+    write_scalars_csv(&scalars, &mut bytes).unwrap();
+
+    assert_eq!(String::from_utf8(bytes.clone()).unwrap(), "0\n1\n42\n4294967295\n", "Scalars should be written one decimal per line");
+    assert_eq!(read_scalars_csv::<u32, _>(&bytes[..]).unwrap(), scalars, "Scalars did not round trip through CSV");
+    assert_eq!(read_scalars_csv::<u32, _>(&b"3, 5,\n\n7\n"[..]).unwrap(), vec![3, 5, 7], "Commas, spaces and blank lines should be accepted");
+}
+
+#[test]
+fn test_csv_field_scalars() {
+    let job = generate_job(10);
+    let path = std::env::temp_dir().join(format!("msm_io_test_{}.csv", std::process::id()));
+
+    write_scalars_csv_file(&job.scalars, &path).unwrap();
+    let scalars: Result<Vec<Fr>, _> = read_scalars_csv_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(scalars.unwrap(), job.scalars, "Field scalars did not round trip through CSV");
+}
+
+#[test]
+fn test_csv_with_invalid_scalar() {
+    let result = read_scalars_csv::<u32, _>(&b"1\n2\nthree\n"[..]);
+    assert!(matches!(result, Err(MsmIoError::Format(message)) if message.contains("line 3")), "Invalid scalars should be reported with their line");
+}
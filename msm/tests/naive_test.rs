@@ -1,6 +1,6 @@
-use msm::naive::naive_msm;
+use msm::naive::{naive_msm, naive_msm_field};
 use msm::operations::{add_points,scalar_multiply};
-use ark_mnt4_298::G1Projective;
+use ark_mnt4_298::{G1Projective, Fr};
 use ark_ff::Zero;
 use ark_std::{test_rng, UniformRand};
 
@@ -59,5 +59,21 @@ fn test_naive_msm_with_different_lengths() {
     assert_eq!(panic_result,G1Projective::zero()) 
 }
 
+#[test]
+fn test_naive_msm_field_with_random_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![Fr::rand(&mut rng), Fr::rand(&mut rng)];  // Full-width field scalars
+    // Compare against result from point addition and scalar multiplication
+    let expected_result = add_points(scalar_multiply(points[0], scalars[0]), scalar_multiply(points[1], scalars[1]));
+    assert_eq!(naive_msm_field(&points, &scalars), expected_result, "MSM with field scalars failed");
+}
 
-
+#[test]
+fn test_naive_msm_field_matches_u32_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![182u32, 4294967295];
+    let field_scalars: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from(scalar)).collect();
+    assert_eq!(naive_msm_field(&points, &field_scalars), naive_msm(&points, &scalars), "Field and u32 scalars should give the same MSM");
+}
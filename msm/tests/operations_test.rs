@@ -1,4 +1,5 @@
-use msm::operations::{add_points, scalar_multiply};
+use msm::operations::{add_points, add_mixed, scalar_multiply};
+use ark_ec::CurveGroup;
 use ark_ff::{Zero, One};
 use ark_mnt4_298::{G1Projective, Fr};
 use ark_std::{UniformRand, test_rng};
@@ -93,3 +94,23 @@ fn test_scalar_multiplication_large_scalar() {
     // Simply testing that the operation completes without error
     let _ = scalar_multiply(point, large_scalar);
 }
+
+#[test]
+// Mixed addition with an affine point should match projective addition
+fn test_mixed_addition() {
+    let mut rng = test_rng();
+    let point1 = G1Projective::rand(&mut rng);
+    let point2 = G1Projective::rand(&mut rng);
+    assert_eq!(add_mixed(point1, point2.into_affine()), add_points(point1, point2), "Mixed addition did not match projective addition");
+}
+
+#[test]
+// Mixed addition of a point to itself and to the zero point should handle the special cases of the formula
+fn test_mixed_addition_special_cases() {
+    let mut rng = test_rng();
+    let point = G1Projective::rand(&mut rng);
+    let affine_point = point.into_affine();
+    assert_eq!(add_mixed(point, affine_point), add_points(point, point), "Mixed addition of a point to itself should double it");
+    assert_eq!(add_mixed(G1Projective::zero(), affine_point), point, "Mixed addition to the zero point failed");
+    assert_eq!(add_mixed(point, -affine_point), G1Projective::zero(), "Mixed addition of a point and its negation should return the zero point");
+}
@@ -0,0 +1,103 @@
+use msm::out_of_core::{out_of_core_chunk_size, out_of_core_msm, out_of_core_msm_field, out_of_core_msm_file, write_points, write_points_file, OutOfCoreConfig};
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::error::{MsmError, MsmIoError};
+use ark_mnt4_298::{G1Affine, G1Projective, Fr};
+use ark_ec::CurveGroup;
+use ark_ff::Zero;
+use ark_serialize::{Compress, Validate};
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to serialise points into an in-memory points file
+fn points_file(points: &[G1Projective], compress: Compress) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    write_points(&G1Projective::normalize_batch(points), &mut bytes, compress).unwrap();
+    bytes
+}
+
+#[test]
+// A budget too small for a single point still processes one point per chunk
+fn test_out_of_core_chunk_size() {
+    assert_eq!(out_of_core_chunk_size::<G1Affine>(0, 32, 4), 1, "Chunks should hold at least one point");
+    assert!(out_of_core_chunk_size::<G1Affine>(1 << 20, 32, 4) > out_of_core_chunk_size::<G1Affine>(1 << 20, 32, 1), "Wider windows need fewer partitions per point");
+    assert!(out_of_core_chunk_size::<G1Affine>(1 << 20, 32, 16) < out_of_core_chunk_size::<G1Affine>(1 << 20, 32, 8), "The buckets of wide windows should count against the budget");
+}
+
+#[test]
+fn test_out_of_core_msm_with_empty_file() {
+    let bytes = points_file(&[], Compress::No);
+    let scalars: Vec<u32> = Vec::new();
+    let result = out_of_core_msm::<G1Affine, _>(&bytes[..], &scalars, &OutOfCoreConfig::default()).unwrap();
+    assert_eq!(result, G1Projective::zero(), "MSM over an empty file should return the zero point");
+}
+
+#[test]
+fn test_out_of_core_msm_with_different_lengths() {
+    let bytes = points_file(&generate_points(3), Compress::No);
+    let result = out_of_core_msm::<G1Affine, _>(&bytes[..], &[1, 2], &OutOfCoreConfig::default());
+    assert!(matches!(result, Err(MsmIoError::Msm(MsmError::LengthMismatch { points: 3, scalars: 2 }))), "Different lengths should be rejected");
+}
+
+#[test]
+// A file cut short should report a serialisation error instead of panicking
+fn test_out_of_core_msm_with_truncated_file() {
+    let mut bytes = points_file(&generate_points(4), Compress::No);
+    bytes.truncate(bytes.len() - 10);
+    let result = out_of_core_msm::<G1Affine, _>(&bytes[..], &generate_scalars(4), &OutOfCoreConfig::default());
+    assert!(matches!(result, Err(MsmIoError::Serialization(_))), "A truncated file should be rejected");
+}
+
+#[test]
+// Budgets from a single point per chunk up to the whole input should all match naive MSM
+fn test_out_of_core_msm_with_memory_budgets() {
+    let points = generate_points(50);
+    let scalars = generate_scalars(50);
+    let bytes = points_file(&points, Compress::No);
+    let expected_result = naive_msm(&points, &scalars);
+
+    for memory_budget in [0, 1 << 10, 1 << 12, 1 << 30] {
+        let config = OutOfCoreConfig { memory_budget, window_size: Some(4), ..OutOfCoreConfig::default() };
+        let result = out_of_core_msm::<G1Affine, _>(&bytes[..], &scalars, &config).unwrap();
+        assert_eq!(result, expected_result, "Out-of-core MSM with a budget of {} bytes did not match naive MSM", memory_budget);
+    }
+}
+
+#[test]
+// Compressed points files should give the same result, with the window chosen automatically
+fn test_out_of_core_msm_field_with_compressed_points() {
+    let mut rng = test_rng();
+    let points = generate_points(30);
+    let scalars: Vec<Fr> = (0..30).map(|_| Fr::rand(&mut rng)).collect();
+    let bytes = points_file(&points, Compress::Yes);
+
+    let config = OutOfCoreConfig { memory_budget: 2048, compress: Compress::Yes, validate: Validate::No, ..OutOfCoreConfig::default() };
+    let result = out_of_core_msm_field::<G1Affine, _>(&bytes[..], &scalars, &config).unwrap();
+    assert_eq!(result, naive_msm_field(&points, &scalars), "Out-of-core MSM with compressed points did not match naive MSM");
+}
+
+#[test]
+// Points written to disk should be read back through a buffered file reader
+fn test_out_of_core_msm_file() {
+    let points = generate_points(40);
+    let scalars = generate_scalars(40);
+    let path = std::env::temp_dir().join(format!("msm_out_of_core_test_{}.bin", std::process::id()));
+
+    write_points_file(&G1Projective::normalize_batch(&points), &path, Compress::No).unwrap();
+    let config = OutOfCoreConfig { memory_budget: 4096, ..OutOfCoreConfig::default() };
+    let result = out_of_core_msm_file::<G1Affine>(&path, &scalars, &config);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(result.unwrap(), naive_msm(&points, &scalars), "Out-of-core MSM over a file did not match naive MSM");
+}
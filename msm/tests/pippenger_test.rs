@@ -1,8 +1,8 @@
-use msm::pippenger::{pippenger, MsmPartition, partition_msm, compute_msm_for_partition, combine_partitioned_msm};
-use msm::naive::naive_msm;
+use msm::pippenger::{pippenger, MsmPartition, partition_msm, compute_msm_for_partition, combine_partitioned_msm, pippenger_field, partition_msm_field};
+use msm::naive::{naive_msm, naive_msm_field};
 use msm::operations::add_points;
-use ark_mnt4_298::G1Projective;
-use ark_ff::Zero;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::{PrimeField, Zero};
 use ark_std::{test_rng, UniformRand};
 use rand::{Rng, thread_rng};
 
@@ -190,4 +190,34 @@ fn test_pippenger_algorithm_2() {
     // Compare against result from naive msm
     let expected_result = naive_msm(&points, &scalars);
     assert_eq!(msm_result, expected_result, "Pippenger algorithm did not match expected result");
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Test for Step 1 with field scalars: Number of partitions should be ceil(b/c), where b is the bit size of the field modulus
+fn test_partition_msm_field() {
+    let scalars = generate_field_scalars(3);
+    let window_size = 4;
+    let partitions = partition_msm_field(&scalars, window_size);
+
+    // MNT4-298 scalars are 298 bits, so ceil(298/4) = 75 partitions
+    assert_eq!(partitions.len(), (Fr::MODULUS_BIT_SIZE as usize).div_ceil(window_size), "Incorrect number of partitions");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+
+    let msm_result = pippenger_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "pippenger with field scalars did not match expected result");
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+use msm::algorithm::{algorithms, get_algorithm, MsmConfig};
+use msm::naive::{naive_msm, naive_msm_field};
+#[cfg(feature = "profiling")]
+use msm::pippenger::pippenger;
+use msm::profiling::{profile, Stage, StageTimings};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+#[cfg(feature = "profiling")]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to profile one registry algorithm on u32 scalars
+fn timings_for(name: &str) -> StageTimings {
+    let points = generate_points(64);
+    let scalars = generate_scalars(64);
+    let config = MsmConfig { window_size: Some(4), ..MsmConfig::default() };
+    get_algorithm::<G1Projective>(name).unwrap().compute_with_timings(&points, &scalars, &config).1
+}
+
+#[test]
+// Profiling should not change the result of any algorithm
+fn test_compute_with_timings_matches_naive() {
+    let mut rng = test_rng();
+    let points = generate_points(20);
+    let scalars = generate_scalars(20);
+    let field_scalars: Vec<Fr> = (0..20).map(|_| Fr::rand(&mut rng)).collect();
+    let config = MsmConfig { window_size: Some(3), ..MsmConfig::default() };
+    let expected_result = naive_msm(&points, &scalars);
+    let expected_field_result = naive_msm_field(&points, &field_scalars);
+
+    for algorithm in algorithms::<G1Projective>() {
+        assert_eq!(algorithm.compute_with_timings(&points, &scalars, &config).0, expected_result, "{} did not match naive MSM", algorithm.name());
+        assert_eq!(algorithm.compute_field_with_timings(&points, &field_scalars, &config).0, expected_field_result, "{} did not match naive MSM", algorithm.name());
+    }
+}
+
+#[test]
+// The total should be the sum of every stage
+fn test_total_and_get() {
+    let timings = StageTimings {
+        partition: Duration::from_micros(1),
+        decompose: Duration::from_micros(2),
+        bucket_fill: Duration::from_micros(3),
+        bucket_reduction: Duration::from_micros(4),
+        window_combination: Duration::from_micros(5),
+    };
+
+    assert_eq!(timings.total(), Duration::from_micros(15), "Total should sum every stage");
+    assert_eq!(timings.get(Stage::BucketFill), Duration::from_micros(3), "get should read the matching stage");
+    assert_eq!(timings.get(Stage::WindowCombination), Duration::from_micros(5), "get should read the matching stage");
+}
+
+#[test]
+// Work outside any stage timer should not be reported
+fn test_profile_without_stages() {
+    let (result, timings) = profile(|| 1 + 1);
+    assert_eq!(result, 2, "profile should return the result of its closure");
+    assert_eq!(timings, StageTimings::default(), "No stage should be reported");
+}
+
+#[test]
+#[cfg(not(feature = "profiling"))]
+// Without the profiling feature every report is zero
+fn test_timings_are_zero_without_feature() {
+    assert_eq!(timings_for("sid_pippenger"), StageTimings::default(), "Timings should be zero without the feature");
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// Pippenger has every stage except decomposition
+fn test_pippenger_stages() {
+    let timings = timings_for("pippenger");
+
+    assert!(timings.partition > Duration::ZERO, "Partitioning should be timed");
+    assert_eq!(timings.decompose, Duration::ZERO, "Pippenger has no decomposition");
+    assert!(timings.bucket_fill > Duration::ZERO, "Bucket fill should be timed");
+    assert!(timings.bucket_reduction > Duration::ZERO, "Bucket reduction should be timed");
+    assert!(timings.window_combination > Duration::ZERO, "Window combination should be timed");
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// The signed integer decomposition variants also time their decomposition
+fn test_sid_stages() {
+    for name in ["sid_pippenger", "parallel_sid_pippenger", "sid_subsum_pippenger", "parallel_sid_subsum_pippenger", "flat_sid_pippenger", "sid_batch_affine_pippenger"] {
+        let timings = timings_for(name);
+        assert!(timings.decompose > Duration::ZERO, "{} should time its decomposition", name);
+        assert!(timings.bucket_reduction > Duration::ZERO, "{} should time its bucket reduction", name);
+    }
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// Straus and wNAF have no buckets, only recoding and the shared doubling chain
+fn test_interleaved_stages() {
+    for name in ["straus", "wnaf"] {
+        let timings = timings_for(name);
+        assert!(timings.decompose > Duration::ZERO, "{} should time its recoding", name);
+        assert!(timings.window_combination > Duration::ZERO, "{} should time its doubling chain", name);
+        assert_eq!(timings.bucket_fill + timings.bucket_reduction, Duration::ZERO, "{} has no buckets", name);
+    }
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// Every bucket-based algorithm in the registry should report its bucket stages
+fn test_bucket_algorithms_report_buckets() {
+    for name in ["pippenger", "parallel_pippenger", "subsum_pippenger", "parallel_subsum_pippenger", "chunked_pippenger", "hybrid_pippenger", "batch_affine_pippenger", "flat_pippenger", "booth_pippenger"] {
+        let timings = timings_for(name);
+        assert!(timings.partition > Duration::ZERO, "{} should time its partitioning", name);
+        assert!(timings.bucket_fill > Duration::ZERO, "{} should time its bucket fill", name);
+        assert!(timings.bucket_reduction > Duration::ZERO, "{} should time its bucket reduction", name);
+    }
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// An inner profile should report its own stages and the outer profile should include them, rather than wait for each other
+fn test_nested_profiles() {
+    let points = generate_points(64);
+    let scalars = generate_scalars(64);
+    let ((_, inner), outer) = profile(|| profile(|| pippenger(&points, &scalars, 4)));
+
+    assert!(inner.bucket_fill > Duration::ZERO, "The inner profile should time its bucket fill");
+    assert!(outer.bucket_fill >= inner.bucket_fill, "The outer profile should include the inner one");
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// Stage timers of an unprofiled MSM on another thread should not be reported by a concurrent profile
+fn test_profile_ignores_other_threads() {
+    let points = generate_points(64);
+    let scalars = generate_scalars(64);
+    let done = AtomicBool::new(false);
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            while !done.load(Ordering::SeqCst) {
+                let _ = pippenger(&points, &scalars, 4);
+            }
+        });
+        for _ in 0..20 {
+            let (_, timings) = profile(|| std::thread::sleep(Duration::from_millis(1)));
+            assert_eq!(timings, StageTimings::default(), "No stage should be reported");
+        }
+        done.store(true, Ordering::SeqCst);
+    });
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+// Parallel variants given a thread count should still report the stages run on that pool
+fn test_profile_with_thread_count() {
+    let points = generate_points(64);
+    let scalars = generate_scalars(64);
+    let config = MsmConfig { window_size: Some(4), num_threads: Some(2) };
+    let timings = get_algorithm::<G1Projective>("parallel_pippenger").unwrap().compute_with_timings(&points, &scalars, &config).1;
+
+    assert!(timings.bucket_fill > Duration::ZERO, "Bucket fill on the pool should be timed");
+    assert!(timings.bucket_reduction > Duration::ZERO, "Bucket reduction on the pool should be timed");
+}
@@ -0,0 +1,57 @@
+use msm::scalar::{bigint_window, num_partitions};
+use ark_mnt4_298::Fr;
+use ark_ff::{BigInteger, BigInteger320, PrimeField};
+use ark_std::{test_rng, UniformRand};
+
+#[test]
+// Number of partitions should round up when window_size does not divide the scalar width
+fn test_num_partitions() {
+    assert_eq!(num_partitions(32, 2), 16, "Incorrect number of partitions");
+    assert_eq!(num_partitions(32, 3), 11, "Incorrect number of partitions");
+    assert_eq!(num_partitions(298, 16), 19, "Incorrect number of partitions");
+    assert_eq!(num_partitions(753, 1), 753, "Incorrect number of partitions");
+}
+
+#[test]
+// Windows inside a single limb should match shifting and masking
+fn test_bigint_window_single_limb() {
+    let scalar = BigInteger320::from(0b1011_0110u64);
+    assert_eq!(bigint_window(&scalar, 0, 2), 0b10, "Incorrect window value");
+    assert_eq!(bigint_window(&scalar, 2, 2), 0b01, "Incorrect window value");
+    assert_eq!(bigint_window(&scalar, 4, 4), 0b1011, "Incorrect window value");
+}
+
+#[test]
+// Windows that straddle a limb boundary should combine bits from both limbs
+fn test_bigint_window_across_limbs() {
+    let scalar = BigInteger320::new([1u64 << 63, 0b101, 0, 0, 0]);
+    // Bits 62..=66 are 0b10110 read from most to least significant
+    assert_eq!(bigint_window(&scalar, 62, 5), 0b10110, "Incorrect window value across limbs");
+}
+
+#[test]
+// Windows starting beyond the most significant limb should be zero
+fn test_bigint_window_past_top_limb() {
+    let scalar = BigInteger320::new([u64::MAX; 5]);
+    assert_eq!(bigint_window(&scalar, 320, 4), 0, "Window past the top limb should be zero");
+    assert_eq!(bigint_window(&scalar, 318, 4), 0b11, "Window overlapping the top limb should keep only the remaining bits");
+}
+
+#[test]
+// Recombining every window of a random field scalar should give back the scalar
+fn test_bigint_window_recombines_scalar() {
+    let mut rng = test_rng();
+    let window_size = 7;
+    for _ in 0..10 {
+        let scalar = Fr::rand(&mut rng).into_bigint();
+        let mut recombined = Fr::from(0u64);
+        for partition_index in (0..num_partitions(Fr::MODULUS_BIT_SIZE as usize, window_size)).rev() {
+            for _ in 0..window_size {
+                recombined = recombined + recombined;
+            }
+            recombined += Fr::from(bigint_window(&scalar, partition_index * window_size, window_size));
+        }
+        assert_eq!(recombined.into_bigint(), scalar, "Windows did not recombine to the original scalar");
+        assert!(scalar.num_bits() <= Fr::MODULUS_BIT_SIZE, "Scalar exceeds the modulus bit size");
+    }
+}
@@ -0,0 +1,105 @@
+use msm::sid_affine_pippenger::{sid_affine_pippenger, sid_affine_pippenger_field, sid_affine_compute_msm_for_partition};
+use msm::sid_pippenger::SidMsmPartitionDecomposed;
+use msm::naive::{naive_msm, naive_msm_field};
+use ark_mnt4_298::{G1Affine, G1Projective, Fr};
+use ark_ec::{CurveGroup, Group};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+fn test_sid_affine_pippenger_with_zero_scalars() {
+    let points = G1Projective::normalize_batch(&generate_points(2));
+    let scalars = vec![0, 0];
+    let window_size = 2;
+    assert_eq!(sid_affine_pippenger(&points, &scalars, window_size), G1Projective::zero(), "SID Affine Pippenger with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_sid_affine_pippenger_with_max_scalars() {
+    let points = generate_points(2);
+    let affine_points = G1Projective::normalize_batch(&points);
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 4;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(sid_affine_pippenger(&affine_points, &scalars, window_size), expected_result, "SID Affine Pippenger with maximum scalars failed");
+}
+
+#[test]
+fn test_sid_affine_pippenger_with_empty_lists() {
+    let points: Vec<G1Affine> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 2;
+    assert_eq!(sid_affine_pippenger(&points, &scalars, window_size), G1Projective::zero(), "SID Affine Pippenger with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_sid_affine_pippenger_with_different_lengths() {
+    let points = G1Projective::normalize_batch(&generate_points(1));
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = sid_affine_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+// Test for Step 2: Negative window values should subtract their points
+fn test_sid_affine_compute_msm_for_partition() {
+    let points = generate_points(4);
+    let affine_points = G1Projective::normalize_batch(&points);
+    let partition = SidMsmPartitionDecomposed { bit_index: 0, window_values: vec![2, -2, 1, -1] };
+    let window_size = 2;
+
+    let msm_result = sid_affine_compute_msm_for_partition(&partition, &affine_points, window_size);
+    // Compare against 2P0 - 2P1 + P2 - P3
+    let expected_result = points[0].double() - points[1].double() + points[2] - points[3];
+    assert_eq!(msm_result, expected_result, "MSM computation for partition failed");
+}
+
+#[test]
+// "Comprehensive test with 1000 points"
+fn test_sid_affine_pippenger_algorithm() {
+    let points = generate_points(1000);
+    let affine_points = G1Projective::normalize_batch(&points);
+    let scalars = generate_scalars(1000);
+    let window_size = 6;
+
+    let msm_result = sid_affine_pippenger(&affine_points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "SID Affine Pippenger did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_sid_affine_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let affine_points = G1Projective::normalize_batch(&points);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+
+    let msm_result = sid_affine_pippenger_field(&affine_points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "SID Affine Pippenger with field scalars did not match expected result");
+}
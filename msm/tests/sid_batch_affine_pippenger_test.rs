@@ -0,0 +1,109 @@
+use msm::sid_batch_affine_pippenger::{sid_batch_affine_pippenger, sid_batch_affine_pippenger_field, sid_batch_affine_compute_msm_for_partition};
+use msm::sid_pippenger::SidMsmPartitionDecomposed;
+use msm::naive::{naive_msm, naive_msm_field};
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ec::{CurveGroup, Group};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+#[test]
+fn test_sid_batch_affine_pippenger_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];
+    let window_size = 2;
+    assert_eq!(sid_batch_affine_pippenger(&points, &scalars, window_size), G1Projective::zero(), "SID Batch Affine Pippenger with zero scalars should return the zero point");
+}
+
+#[test]
+// A window value of 3 with window_size 2 decomposes to -1, so each point lands in its bucket negated
+fn test_sid_batch_affine_pippenger_with_negative_digits() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![3, 15];
+    let window_size = 2;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(sid_batch_affine_pippenger(&points, &scalars, window_size), expected_result, "SID Batch Affine Pippenger with negative digits failed");
+}
+
+#[test]
+fn test_sid_batch_affine_pippenger_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 4;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(sid_batch_affine_pippenger(&points, &scalars, window_size), expected_result, "SID Batch Affine Pippenger with maximum scalars failed");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_sid_batch_affine_pippenger_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 2;
+    let panic_result = sid_batch_affine_pippenger(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Test for Step 2: Signed window values should cancel within the same bucket
+fn test_sid_batch_affine_compute_msm_for_partition() {
+    let points = generate_points(4);
+    let affine_points = G1Projective::normalize_batch(&points);
+    let partition = SidMsmPartitionDecomposed { bit_index: 0, window_values: vec![2, -2, 1, -1] };
+    let window_size = 2;
+
+    let msm_result: G1Projective = sid_batch_affine_compute_msm_for_partition(&partition, &affine_points, window_size);
+    // Compare against 2P0 - 2P1 + P2 - P3
+    let expected_result = points[0].double() - points[1].double() + points[2] - points[3];
+    assert_eq!(msm_result, expected_result, "MSM computation for partition failed");
+}
+
+#[test]
+// "Comprehensive test with 1000 points"
+fn test_sid_batch_affine_pippenger_algorithm() {
+    let points = generate_points(1000);
+    let scalars = generate_scalars(1000);
+    let window_size = 6;
+
+    let msm_result = sid_batch_affine_pippenger(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "SID Batch Affine Pippenger did not match expected result");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_sid_batch_affine_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+
+    let msm_result = sid_batch_affine_pippenger_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "SID Batch Affine Pippenger with field scalars did not match expected result");
+}
@@ -1,8 +1,10 @@
-use msm::naive::naive_msm;
-use msm::sid_pippenger::{SidMsmPartitionDecomposed, sid_decompose_partitions, sid_pippenger, SidMsmPartition, sid_partition_msm, sid_compute_msm_for_partition, sid_combine_partitioned_msm};
+#![allow(clippy::useless_vec, clippy::cast_abs_to_unsigned, clippy::unwrap_or_default)]
+
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::sid_pippenger::{SidMsmPartitionDecomposed, sid_decompose_partitions, sid_pippenger, SidMsmPartition, sid_partition_msm, sid_compute_msm_for_partition, sid_combine_partitioned_msm, sid_pippenger_field, sid_partition_msm_field};
 use msm::operations::add_points;
-use ark_mnt4_298::G1Projective;
-use ark_ff::Zero;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::{PrimeField, Zero};
 use ark_std::{test_rng, UniformRand};
 use rand::{Rng, thread_rng};
 use std::collections::HashMap;
@@ -145,6 +147,29 @@ fn test_sid_decompose_partitions() {
     });
 }
 
+#[test]
+// An empty list of partitions has nothing to decompose
+fn test_sid_decompose_partitions_with_no_partitions() {
+    let decomposed_partitions = sid_decompose_partitions(&[], 4);
+    assert!(decomposed_partitions.is_empty(), "Decomposing no partitions should give no partitions");
+}
+
+#[test]
+// The overflow partition is only appended when a carry reaches it
+fn test_sid_decompose_partitions_overflow() {
+    let window_size = 5;
+    // 32-bit scalars with a 5-bit window leave a 2-bit top window, which can never carry
+    let partitions = sid_partition_msm(&[u32::MAX, 0x8000_0000], window_size);
+    assert_eq!(sid_decompose_partitions(&partitions, window_size).len(), partitions.len(), "A narrow top window should not need an overflow partition");
+
+    // The top 2-bit window of 3 carries out of a 2-bit window
+    let window_size = 2;
+    let partitions = sid_partition_msm(&[u32::MAX], window_size);
+    let decomposed_partitions = sid_decompose_partitions(&partitions, window_size);
+    assert_eq!(decomposed_partitions.len(), partitions.len() + 1, "A carry out of the top window needs an overflow partition");
+    assert_eq!(decomposed_partitions.last().unwrap().window_values, vec![1], "The overflow partition should hold the carry");
+}
+
 #[test]
 // Test for Step 2: Compute MSM for each partition
 fn test_parallel_sid_compute_msm_for_partition() {
@@ -251,4 +276,34 @@ fn test_sid_pippenger_algorithm_2() {
     // Compare against result from naive msm
     let expected_result = naive_msm(&points, &scalars);
     assert_eq!(msm_result, expected_result, "Pippenger algorithm did not match expected result");
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Test for Step 1 with field scalars: Number of partitions should be ceil(b/c), where b is the bit size of the field modulus
+fn test_sid_partition_msm_field() {
+    let scalars = generate_field_scalars(3);
+    let window_size = 4;
+    let partitions = sid_partition_msm_field(&scalars, window_size);
+
+    // MNT4-298 scalars are 298 bits, so ceil(298/4) = 75 partitions
+    assert_eq!(partitions.len(), (Fr::MODULUS_BIT_SIZE as usize).div_ceil(window_size), "Incorrect number of partitions");
+}
+
+#[test]
+// "Comprehensive test with 100 points and full-width field scalars"
+fn test_sid_pippenger_field_algorithm() {
+    let points = generate_points(100);
+    let scalars = generate_field_scalars(100);
+    let window_size = 5;
+
+    let msm_result = sid_pippenger_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "sid_pippenger with field scalars did not match expected result");
 }
\ No newline at end of file
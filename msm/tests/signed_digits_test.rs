@@ -0,0 +1,158 @@
+use msm::signed_digits::{sid_num_windows, SignedDigits};
+use msm::sid_pippenger::{sid_pippenger_with_digits, try_sid_pippenger_with_digits};
+use msm::naive::naive_msm_field;
+use msm::error::MsmError;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::{BigInteger, BigInteger256, BigInteger832, Field, PrimeField, Zero};
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Checks every digit is in range and the digits of each scalar reconstruct it in the scalar field
+fn assert_reconstructs(digits: &SignedDigits, expected: &[Fr]) {
+    let bound = 1i64 << (digits.window_size() - 1);
+    let base = Fr::from(2u64).pow([digits.window_size() as u64]);
+
+    for (i, &scalar) in expected.iter().enumerate() {
+        let reconstructed = (0..digits.num_windows()).rev().fold(Fr::zero(), |acc, k| {
+            let digit = digits.digit(i, k);
+            assert!((-bound..=bound).contains(&digit), "Digit {} is out of range for window size {}", digit, digits.window_size());
+            let digit = if digit < 0 { -Fr::from(digit.unsigned_abs()) } else { Fr::from(digit as u64) };
+            acc * base + digit
+        });
+        assert_eq!(reconstructed, scalar, "Digits do not reconstruct scalar {}", i);
+    }
+}
+
+#[test]
+// The overflow window is only needed when the top window is at least window_size - 1 bits wide
+fn test_sid_num_windows() {
+    assert_eq!(sid_num_windows(32, 2), 17, "A full top window needs an overflow window");
+    assert_eq!(sid_num_windows(32, 3), 12, "A top window of window_size - 1 bits needs an overflow window");
+    assert_eq!(sid_num_windows(32, 5), 7, "A top window of 2 bits never carries out of a 5-bit window");
+    assert_eq!(sid_num_windows(0, 4), 0, "Empty scalars have no windows");
+}
+
+#[test]
+fn test_signed_digits_u32() {
+    let scalars: Vec<u32> = (0..100).map(|_| thread_rng().gen()).chain([0, 1, u32::MAX]).collect();
+    let expected: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from(scalar)).collect();
+
+    for window_size in 2..=16 {
+        assert_reconstructs(&SignedDigits::new(&scalars, window_size), &expected);
+    }
+}
+
+#[test]
+fn test_signed_digits_u64() {
+    let scalars: Vec<u64> = (0..100).map(|_| thread_rng().gen()).chain([0, 1, u64::MAX]).collect();
+    let expected: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from(scalar)).collect();
+
+    for window_size in [2, 7, 13, 31] {
+        assert_reconstructs(&SignedDigits::new(&scalars, window_size), &expected);
+    }
+}
+
+#[test]
+fn test_signed_digits_u128() {
+    let scalars: Vec<u128> = (0..100).map(|_| thread_rng().gen()).chain([0, 1, u128::MAX]).collect();
+    let expected: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from(scalar)).collect();
+
+    for window_size in [2, 5, 16] {
+        assert_reconstructs(&SignedDigits::new(&scalars, window_size), &expected);
+    }
+}
+
+#[test]
+// BigInteger256 values are reduced mod r on reconstruction, which is fine as the digits are checked in the scalar field
+fn test_signed_digits_bigint256() {
+    let mut rng = test_rng();
+    let scalars: Vec<BigInteger256> = (0..50).map(|_| BigInteger256::new([rng.gen(), rng.gen(), rng.gen(), rng.gen()])).collect();
+    let expected: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from_le_bytes_mod_order(&scalar.to_bytes_le())).collect();
+
+    let digits = SignedDigits::new(&scalars, 11);
+    assert_eq!(digits.num_windows(), sid_num_windows(256, 11), "Incorrect number of windows for 256-bit scalars");
+    assert_reconstructs(&digits, &expected);
+}
+
+#[test]
+// Only the low limbs are set so the 832-bit values stay below the scalar field modulus
+fn test_signed_digits_bigint832() {
+    let mut rng = test_rng();
+    let scalars: Vec<BigInteger832> = (0..20).map(|_| {
+        let mut limbs = [0u64; 13];
+        limbs[0] = rng.gen();
+        limbs[1] = rng.gen();
+        BigInteger832::new(limbs)
+    }).collect();
+    let expected: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from_le_bytes_mod_order(&scalar.to_bytes_le())).collect();
+
+    let digits = SignedDigits::new(&scalars, 9);
+    assert_eq!(digits.num_windows(), sid_num_windows(832, 9), "Incorrect number of windows for 832-bit scalars");
+    assert_reconstructs(&digits, &expected);
+}
+
+#[test]
+fn test_signed_digits_field() {
+    let mut rng = test_rng();
+    let scalars: Vec<Fr> = (0..50).map(|_| Fr::rand(&mut rng)).collect();
+
+    let digits = SignedDigits::from_field(&scalars, 8);
+    assert_eq!(digits.scalar_bits(), Fr::MODULUS_BIT_SIZE as usize, "Field digits should cover the bits of the modulus");
+    assert_reconstructs(&digits, &scalars);
+}
+
+#[test]
+// Decomposing no scalars should not panic
+fn test_signed_digits_with_empty_scalars() {
+    let scalars: Vec<u64> = Vec::new();
+    let digits = SignedDigits::new(&scalars, 4);
+
+    assert_eq!(digits.num_scalars(), 0, "There should be no scalars");
+    assert!(digits.to_partitions().iter().all(|partition| partition.window_values.is_empty()), "Partitions should be empty");
+}
+
+#[test]
+fn test_try_signed_digits_with_window_size_one() {
+    let scalars = vec![1u64];
+    assert!(matches!(SignedDigits::try_with_scalar_bits(&scalars, 1, 64), Err(MsmError::InvalidWindowSize { window_size: 1, .. })), "Window size 1 should be rejected");
+}
+
+#[test]
+// SID Pippenger should accept digits of 64-bit scalars
+fn test_sid_pippenger_with_u64_digits() {
+    let points = generate_points(50);
+    let scalars: Vec<u64> = (0..50).map(|_| thread_rng().gen()).collect();
+    let field_scalars: Vec<Fr> = scalars.iter().map(|&scalar| Fr::from(scalar)).collect();
+    let expected_result = naive_msm_field(&points, &field_scalars);
+
+    for window_size in [2, 6, 10] {
+        let digits = SignedDigits::new(&scalars, window_size);
+        assert_eq!(sid_pippenger_with_digits(&points, &digits), expected_result, "SID Pippenger with u64 digits did not match naive MSM");
+    }
+}
+
+#[test]
+// SID Pippenger should accept digits of full-width field scalars
+fn test_sid_pippenger_with_field_digits() {
+    let mut rng = test_rng();
+    let points = generate_points(30);
+    let scalars: Vec<Fr> = (0..30).map(|_| Fr::rand(&mut rng)).collect();
+
+    let digits = SignedDigits::from_field(&scalars, 7);
+    assert_eq!(sid_pippenger_with_digits(&points, &digits), naive_msm_field(&points, &scalars), "SID Pippenger with field digits did not match naive MSM");
+}
+
+#[test]
+// Digits wider than the scalar field would be silently reduced
+fn test_try_sid_pippenger_with_wide_digits() {
+    let points = generate_points(1);
+    let scalars = vec![BigInteger832::from(1u64)];
+    let digits = SignedDigits::new(&scalars, 8);
+    assert_eq!(try_sid_pippenger_with_digits(&points, &digits), Err(MsmError::ScalarTooWide { scalar_bits: 832, max_bits: Fr::MODULUS_BIT_SIZE as usize }), "832-bit digits should be rejected");
+}
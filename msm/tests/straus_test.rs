@@ -0,0 +1,167 @@
+use msm::straus::{straus, straus_field, try_straus, sliding_window_digits, sliding_window_digits_field, straus_precompute_odd_multiples, straus_combine};
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::error::MsmError;
+use msm::window::MAX_STRAUS_WINDOW_SIZE;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+#[test]
+fn test_straus_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];
+    let window_size = 3;
+    assert_eq!(straus(&points, &scalars, window_size), G1Projective::zero(), "Straus with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_straus_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 4;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(straus(&points, &scalars, window_size), expected_result, "Straus with maximum scalars failed");
+}
+
+#[test]
+fn test_straus_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 3;
+    assert_eq!(straus(&points, &scalars, window_size), G1Projective::zero(), "Straus with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_straus_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 3;
+    let panic_result = straus(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+fn test_try_straus_with_invalid_window_size() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1];
+    assert_eq!(try_straus(&points, &scalars, 0), Err(MsmError::InvalidWindowSize { window_size: 0, min: 1, max: MAX_STRAUS_WINDOW_SIZE }), "Zero window size should be rejected");
+    // Every point would store 2^19 odd multiples, so the window is rejected instead of exhausting memory
+    assert_eq!(try_straus(&points, &scalars, 20), Err(MsmError::InvalidWindowSize { window_size: 20, min: 1, max: MAX_STRAUS_WINDOW_SIZE }), "Oversized window should be rejected");
+    assert!(try_straus(&points, &scalars, MAX_STRAUS_WINDOW_SIZE).is_ok(), "The largest Straus window should be accepted");
+}
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// Test for Step 1: 0b1011_0110 with windows of 3 bits starts windows at bits 1 (0b011) and 4 (0b011), then bit 7 (0b1)
+fn test_sliding_window_digits() {
+    let digits = sliding_window_digits(0b1011_0110, 3);
+
+    assert_eq!(digits.len(), 32, "There should be one digit per bit");
+    assert_eq!(digits[1], 0b011, "Incorrect digit at bit 1");
+    assert_eq!(digits[4], 0b011, "Incorrect digit at bit 4");
+    assert_eq!(digits[7], 0b1, "Incorrect digit at bit 7");
+    assert_eq!(digits.iter().filter(|&&digit| digit != 0).count(), 3, "There should be three non-zero digits");
+}
+
+#[test]
+// Test for Step 1: Every digit should be odd and below 2^window_size, and the digits should reconstruct the scalar
+fn test_sliding_window_digits_reconstruct_scalar() {
+    for scalar in generate_scalars(100).into_iter().chain([0, 1, u32::MAX]) {
+        for window_size in 1..=6 {
+            let digits = sliding_window_digits(scalar, window_size);
+            let mut reconstructed = 0u64;
+            for (bit_index, &digit) in digits.iter().enumerate() {
+                assert!(digit == 0 || (digit % 2 == 1 && digit < 1 << window_size), "Digit {} is not an odd value below 2^{}", digit, window_size);
+                reconstructed += (digit as u64) << bit_index;
+            }
+            assert_eq!(reconstructed, scalar as u64, "Digits do not reconstruct {} with window size {}", scalar, window_size);
+        }
+    }
+}
+
+#[test]
+// Test for Step 1 with field scalars: There should be one digit per bit of the field modulus
+fn test_sliding_window_digits_field() {
+    let scalar = Fr::from(0b1011_0110u64);
+    let digits = sliding_window_digits_field(&scalar, 3);
+
+    assert_eq!(digits.len(), 298, "There should be one digit per bit of the field modulus");
+    assert_eq!(digits[..32], sliding_window_digits(0b1011_0110, 3)[..], "Small field scalars should match u32 recoding");
+}
+
+#[test]
+// Test for Step 2: The table should hold the odd multiples P, 3P, 5P, 7P
+fn test_straus_precompute_odd_multiples() {
+    let point = generate_points(1)[0];
+    let multiples = straus_precompute_odd_multiples(point, 3);
+
+    assert_eq!(multiples.len(), 4, "There should be 2^(window_size - 1) odd multiples");
+    for (i, &multiple) in multiples.iter().enumerate() {
+        assert_eq!(multiple, point * Fr::from(2 * i as u64 + 1), "Incorrect multiple {}P", 2 * i + 1);
+    }
+}
+
+#[test]
+// Test for Step 3: The shared doubling chain should combine the digits of every point
+fn test_straus_combine() {
+    let points = generate_points(10);
+    let scalars = generate_scalars(10);
+    let window_size = 4;
+
+    let digits: Vec<Vec<u32>> = scalars.iter().map(|&scalar| sliding_window_digits(scalar, window_size)).collect();
+    let combined_result = straus_combine(&points, &digits, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(combined_result, expected_result, "Combined MSM result is incorrect");
+}
+
+#[test]
+// "Comprehensive test with 30 points", just below the dispatcher threshold
+fn test_straus_algorithm() {
+    let points = generate_points(30);
+    let scalars = generate_scalars(30);
+
+    for window_size in 1..=6 {
+        let msm_result = straus(&points, &scalars, window_size);
+        // Compare against result from naive msm
+        let expected_result = naive_msm(&points, &scalars);
+        assert_eq!(msm_result, expected_result, "Straus with window size {} did not match expected result", window_size);
+    }
+}
+
+#[test]
+// "Comprehensive test with 20 points and full-width field scalars"
+fn test_straus_field_algorithm() {
+    let points = generate_points(20);
+    let scalars = generate_field_scalars(20);
+    let window_size = 5;
+
+    let msm_result = straus_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "Straus with field scalars did not match expected result");
+}
@@ -0,0 +1,65 @@
+use msm::thread_pool::{install, thread_pool, try_install};
+use std::sync::Arc;
+use msm::parallel_pippenger::parallel_pippenger;
+use msm::parallel_sid_subsum_pippenger::parallel_sid_subsum_pippenger;
+use msm::naive::naive_msm;
+use ark_mnt4_298::G1Projective;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+#[test]
+// A fixed thread count should size the pool the closure runs on
+fn test_install_with_fixed_thread_count() {
+    assert_eq!(install(Some(1), rayon::current_num_threads), 1, "Pool should have one thread");
+    assert_eq!(install(Some(3), rayon::current_num_threads), 3, "Pool should have three threads");
+}
+
+#[test]
+// Pools should be built once per thread count and reused by later calls
+fn test_pools_are_cached() {
+    let first = thread_pool(2).unwrap();
+    let second = thread_pool(2).unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second), "The same thread count should reuse its pool");
+    assert!(!Arc::ptr_eq(&first, &thread_pool(3).unwrap()), "Different thread counts should use different pools");
+    assert_eq!(try_install(Some(2), rayon::current_num_threads), Ok(2), "try_install should run on the cached pool");
+}
+
+#[test]
+// Without a thread count the closure should run on the global pool
+fn test_install_without_thread_count() {
+    assert_eq!(install(None, rayon::current_num_threads), rayon::current_num_threads(), "Closure should run on the global pool");
+}
+
+#[test]
+// Parallel Pippenger on a single worker thread should still match naive MSM
+fn test_parallel_pippenger_on_single_thread() {
+    let points = generate_points(100);
+    let scalars = generate_scalars(100);
+    let expected_result = naive_msm(&points, &scalars);
+
+    assert_eq!(install(Some(1), || parallel_pippenger(&points, &scalars, 4)), expected_result, "Parallel Pippenger did not match naive MSM on one thread");
+}
+
+#[test]
+// More threads than partitions should not change the result
+fn test_parallel_sid_subsum_pippenger_with_more_threads_than_partitions() {
+    let points = generate_points(100);
+    let scalars = generate_scalars(100);
+    let expected_result = naive_msm(&points, &scalars);
+
+    // Window size 16 gives only 3 partitions after decomposition
+    assert_eq!(install(Some(8), || parallel_sid_subsum_pippenger(&points, &scalars, 16)), expected_result, "Parallel SID Subsum Pippenger did not match naive MSM on eight threads");
+}
@@ -1,7 +1,7 @@
-use msm::trivial::trivial_msm;
-use msm::naive::naive_msm;
+use msm::trivial::{trivial_msm, trivial_msm_field};
+use msm::naive::{naive_msm, naive_msm_field};
 use msm::operations::{add_points,scalar_multiply};
-use ark_mnt4_298::G1Projective;
+use ark_mnt4_298::{G1Projective, Fr};
 use ark_ff::Zero;
 use ark_std::{test_rng, UniformRand};
 
@@ -60,5 +60,20 @@ fn test_trivial_msm_with_different_lengths() {
     assert_eq!(panic_result,G1Projective::zero())
 }
 
+#[test]
+fn test_trivial_msm_field_with_random_scalars() {
+    let mut rng = test_rng();
+    let points: Vec<G1Projective> = (0..10).map(|_| G1Projective::rand(&mut rng)).collect();
+    let scalars: Vec<Fr> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(trivial_msm_field(&points, &scalars), expected_result, "Trivial MSM with field scalars failed");
+}
 
-
+#[test]
+fn test_trivial_msm_field_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![Fr::zero(), Fr::zero()];
+    assert_eq!(trivial_msm_field(&points, &scalars), G1Projective::zero(), "MSM with zero field scalars should be the zero point");
+}
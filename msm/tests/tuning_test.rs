@@ -0,0 +1,174 @@
+use msm::algorithm::{get_algorithm, select_algorithm, MsmConfig, STRAUS_MAX_POINTS};
+use msm::error::MsmIoError;
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::tuning::{install_profile, install_profile_file, read_profiles, read_profiles_file, remove_profile, save_profile_file, tune, tuned_entry, write_profiles, TuningConfig, TuningEntry, TuningProfile};
+use ark_ec::CurveGroup;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// The dispatcher's profiles are shared by every test in this file, so each test that installs one uses its own curve group
+
+// Helper function to generate n points
+fn generate_points<G: CurveGroup>(num_points: usize) -> Vec<G> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to build an entry
+fn entry(num_points: usize, algorithm: &str, window_size: Option<usize>) -> TuningEntry {
+    TuningEntry { num_points, algorithm: algorithm.to_string(), window_size }
+}
+
+#[test]
+// The tuner should pick one valid configuration per size and scalar type, and each should match naive MSM
+fn test_tune_small_grid() {
+    type G = ark_mnt4_298::G1Projective;
+    let config = TuningConfig { sizes: vec![16, 4], windows: 1..=3, runs: 1, ..TuningConfig::default() };
+    let profile = tune::<G>("mnt4-298", &config);
+
+    assert_eq!(profile.group, "mnt4-298", "Profile should record the tuned group");
+    assert_eq!(profile.entries.iter().map(|entry| entry.num_points).collect::<Vec<_>>(), vec![4, 16], "Entries should be sorted by size");
+    assert_eq!(profile.field_entries.len(), 2, "Field scalars should be tuned for every size");
+
+    let mut rng = test_rng();
+    let points = generate_points::<G>(16);
+    let scalars = generate_scalars(16);
+    let field_scalars: Vec<ark_mnt4_298::Fr> = (0..16).map(|_| UniformRand::rand(&mut rng)).collect();
+    for tuned in profile.entries.iter().chain(&profile.field_entries) {
+        assert_ne!(tuned.algorithm, "auto", "The dispatcher should not tune itself");
+        if let Some(window_size) = tuned.window_size {
+            assert!((1..=3).contains(&window_size), "Window {} is outside the grid", window_size);
+        }
+
+        let algorithm = get_algorithm::<G>(&tuned.algorithm).expect("Tuned algorithm should be registered");
+        let config = MsmConfig { window_size: tuned.window_size, ..MsmConfig::default() };
+        assert_eq!(algorithm.compute(&points, &scalars, &config), naive_msm(&points, &scalars), "{} did not match naive MSM", tuned);
+        assert_eq!(algorithm.compute_field(&points, &field_scalars, &config), naive_msm_field(&points, &field_scalars), "{} did not match naive MSM", tuned);
+    }
+}
+
+#[test]
+// Field scalars are skipped when not requested
+fn test_tune_u32_only() {
+    let config = TuningConfig { sizes: vec![4], windows: 2..=2, runs: 1, field_scalars: false, ..TuningConfig::default() };
+    let profile = tune::<ark_mnt4_298::G1Projective>("mnt4-298", &config);
+
+    assert_eq!(profile.entries.len(), 1, "u32 scalars should be tuned");
+    assert!(profile.field_entries.is_empty(), "Field scalars should not be tuned");
+}
+
+#[test]
+// Lookup should use the largest measured size not above the input, and the smallest size below every measured size
+fn test_lookup() {
+    let profile = TuningProfile::new(
+        "mnt4-298",
+        vec![entry(1024, "pippenger", Some(8)), entry(16, "straus", Some(4))],
+        vec![],
+    );
+
+    assert_eq!(profile.lookup(1, false), Some(&entry(16, "straus", Some(4))), "Small inputs should use the smallest size");
+    assert_eq!(profile.lookup(1023, false), Some(&entry(16, "straus", Some(4))), "Inputs below a size should use the size before it");
+    assert_eq!(profile.lookup(1024, false), Some(&entry(1024, "pippenger", Some(8))), "Inputs at a size should use it");
+    assert_eq!(profile.lookup(1 << 20, false), Some(&entry(1024, "pippenger", Some(8))), "Large inputs should use the largest size");
+    assert_eq!(profile.lookup(1024, true), None, "Field scalars were not tuned");
+}
+
+#[test]
+// Profiles should survive a round trip through JSON
+fn test_profiles_round_trip() {
+    let profiles = vec![
+        TuningProfile::new("mnt4-298", vec![entry(16, "naive", None)], vec![entry(16, "sid_pippenger", Some(3))]),
+        TuningProfile::new("mnt6-753", vec![entry(64, "booth_pippenger", Some(5))], vec![]),
+    ];
+    let mut bytes = Vec::new();
+    write_profiles(&profiles, &mut bytes).unwrap();
+
+    assert_eq!(read_profiles(&bytes[..]).unwrap(), profiles, "Profiles changed in the round trip");
+}
+
+#[test]
+// Malformed profile files should be rejected with a format error
+fn test_read_invalid_profiles() {
+    for json in [r#"{"entries": []}"#, r#"{"profiles": [{"entries": [], "field_entries": []}]}"#, r#"{"profiles": [{"group": "g", "entries": [{"num_points": 4}], "field_entries": []}]}"#] {
+        assert!(matches!(read_profiles(json.as_bytes()), Err(MsmIoError::Format(_))), "{} should be rejected", json);
+    }
+}
+
+#[test]
+// Saving should replace the profile of the same group and keep the profiles of other groups
+fn test_save_profile_file() {
+    let path = std::env::temp_dir().join(format!("msm_tuning_test_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mnt4 = TuningProfile::new("mnt4-298", vec![entry(16, "straus", Some(4))], vec![]);
+    let mnt6 = TuningProfile::new("mnt6-298", vec![entry(16, "wnaf", Some(3))], vec![]);
+    let retuned_mnt4 = TuningProfile::new("mnt4-298", vec![entry(16, "pippenger", Some(2))], vec![]);
+
+    save_profile_file(&mnt4, &path).unwrap();
+    save_profile_file(&mnt6, &path).unwrap();
+    save_profile_file(&retuned_mnt4, &path).unwrap();
+    let profiles = read_profiles_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(profiles, vec![mnt6, retuned_mnt4], "Only the profile of the same group should be replaced");
+}
+
+#[test]
+// An installed profile should decide the dispatcher's algorithm and window, and removing it restores the heuristic
+fn test_dispatcher_uses_installed_profile() {
+    type G = ark_mnt4_298::G2Projective;
+    install_profile::<G>(TuningProfile::new(
+        "mnt4-298-g2",
+        vec![entry(4, "sid_pippenger", Some(3)), entry(64, "naive", None)],
+        vec![entry(4, "booth_pippenger", Some(5))],
+    ));
+
+    assert_eq!(select_algorithm::<G>(1).name(), "sid_pippenger", "Dispatcher should follow the profile below every size");
+    assert_eq!(select_algorithm::<G>(100).name(), "naive", "Dispatcher should follow the profile for large inputs");
+    assert_eq!(tuned_entry::<G>(10, true), Some(entry(4, "booth_pippenger", Some(5))), "Field scalars should use their own entries");
+    assert_eq!(tuned_entry::<ark_mnt6_753::G1Projective>(10, false), None, "Profiles should only apply to their own group");
+
+    let auto = get_algorithm::<G>("auto").unwrap();
+    let mut rng = test_rng();
+    let points = generate_points::<G>(10);
+    let scalars = generate_scalars(10);
+    let field_scalars: Vec<ark_mnt4_298::Fr> = (0..10).map(|_| UniformRand::rand(&mut rng)).collect();
+    assert_eq!(auto.compute(&points, &scalars, &MsmConfig::default()), naive_msm(&points, &scalars), "Tuned dispatcher did not match naive MSM");
+    assert_eq!(auto.compute_field(&points, &field_scalars, &MsmConfig::default()), naive_msm_field(&points, &field_scalars), "Tuned dispatcher did not match naive MSM");
+    // A window given by the caller overrides the tuned one, so sid_pippenger rejects a window of 1
+    assert!(auto.try_compute(&points, &scalars, &MsmConfig { window_size: Some(1), ..MsmConfig::default() }).is_err(), "The caller's window should be used");
+
+    remove_profile::<G>();
+    assert_eq!(select_algorithm::<G>(1).name(), "straus", "Removing the profile should restore the heuristic");
+    assert_eq!(select_algorithm::<G>(STRAUS_MAX_POINTS).name(), "pippenger", "Removing the profile should restore the heuristic");
+}
+
+#[test]
+// Entries naming an unknown algorithm or the dispatcher itself fall back to the heuristic
+fn test_dispatcher_ignores_invalid_entries() {
+    type G = ark_mnt6_298::G2Projective;
+    install_profile::<G>(TuningProfile::new("mnt6-298-g2", vec![entry(1, "unknown", Some(4)), entry(64, "auto", None)], vec![]));
+
+    assert_eq!(select_algorithm::<G>(10).name(), "straus", "Unknown algorithms should fall back to the heuristic");
+    assert_eq!(select_algorithm::<G>(100).name(), "pippenger", "The dispatcher should not dispatch to itself");
+}
+
+#[test]
+// Profiles installed from a file should be used by the dispatcher, and missing files or groups reported
+fn test_install_profile_file() {
+    type G = ark_mnt4_753::G1Projective;
+    let path = std::env::temp_dir().join(format!("msm_tuning_test_install_{}.json", std::process::id()));
+    save_profile_file(&TuningProfile::new("mnt4-753", vec![entry(1, "flat_pippenger", Some(6))], vec![]), &path).unwrap();
+    install_profile_file::<G>(&path, "mnt4-753").unwrap();
+    let missing_group = install_profile_file::<G>(&path, "mnt6-753");
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(select_algorithm::<G>(1000).name(), "flat_pippenger", "Dispatcher should use the installed file");
+    assert!(matches!(missing_group, Err(MsmIoError::Format(_))), "Files without the group should be reported");
+    assert!(matches!(install_profile_file::<G>(&path, "mnt4-753"), Err(MsmIoError::Io(_))), "Missing files should be reported");
+}
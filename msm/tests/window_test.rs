@@ -0,0 +1,97 @@
+use msm::window::{cost_model_window_size, estimated_cost, heuristic_window_size, optimal_window_size, straus_window_size, wnaf_window_size, PippengerVariant, MAX_STRAUS_WINDOW_SIZE};
+use msm::error::{MAX_WINDOW_SIZE, MIN_SID_WINDOW_SIZE, MIN_WINDOW_SIZE};
+
+const VARIANTS: [PippengerVariant; 4] = [PippengerVariant::Standard, PippengerVariant::Sid, PippengerVariant::Subsum, PippengerVariant::SidSubsum];
+
+#[test]
+// Small inputs should use the classic window of 3
+fn test_heuristic_window_size_small_inputs() {
+    assert_eq!(heuristic_window_size(0, 32), 3, "Empty inputs should use a window of 3");
+    assert_eq!(heuristic_window_size(31, 32), 3, "Small inputs should use a window of 3");
+}
+
+#[test]
+// The heuristic window should grow with log(n)
+fn test_heuristic_window_size_grows_with_input() {
+    assert_eq!(heuristic_window_size(1 << 10, 298), 8, "Incorrect window for 2^10 points");
+    assert_eq!(heuristic_window_size(1 << 20, 298), 15, "Incorrect window for 2^20 points");
+}
+
+#[test]
+// Windows should never be wider than the scalars themselves
+fn test_window_size_clamped_to_scalar_bits() {
+    assert_eq!(heuristic_window_size(1 << 20, 8), 8, "Window should not exceed the scalar bit width");
+    for variant in VARIANTS {
+        assert!(optimal_window_size(1 << 20, 8, variant) <= 8, "Window should not exceed the scalar bit width for {:?}", variant);
+    }
+}
+
+#[test]
+// Every chosen window should lie within the range accepted by the try_* entry points
+fn test_window_size_in_valid_range() {
+    for variant in VARIANTS {
+        for log_n in 0..24 {
+            let window_size = optimal_window_size(1 << log_n, 753, variant);
+            assert!((1..=MAX_WINDOW_SIZE).contains(&window_size), "Window {} out of range for {:?}", window_size, variant);
+        }
+        if matches!(variant, PippengerVariant::Sid | PippengerVariant::SidSubsum) {
+            assert!(optimal_window_size(1, 32, variant) >= MIN_SID_WINDOW_SIZE, "SID windows must be at least 2 bits");
+        }
+    }
+}
+
+#[test]
+// The cost model should never pick a narrower window for a larger input
+fn test_cost_model_monotonic_in_input_size() {
+    for variant in [PippengerVariant::Sid, PippengerVariant::Subsum, PippengerVariant::SidSubsum] {
+        let mut previous = 0;
+        for log_n in 0..24 {
+            let window_size = cost_model_window_size(1 << log_n, 298, variant);
+            assert!(window_size >= previous, "Window shrank from {} to {} at 2^{} points for {:?}", previous, window_size, log_n, variant);
+            previous = window_size;
+        }
+    }
+}
+
+#[test]
+// The chosen window should be the minimum of the cost model
+fn test_cost_model_picks_cheapest_window() {
+    let num_points = 1000;
+    let window_size = cost_model_window_size(num_points, 32, PippengerVariant::Sid);
+    let chosen_cost = estimated_cost(num_points, 32, window_size, PippengerVariant::Sid);
+
+    for other in MIN_SID_WINDOW_SIZE..=MAX_WINDOW_SIZE {
+        assert!(chosen_cost <= estimated_cost(num_points, 32, other, PippengerVariant::Sid), "Window {} is cheaper than the chosen window {}", other, window_size);
+    }
+}
+
+#[test]
+// SID halves the buckets, so for the same window it should never cost more per window than unsigned Pippenger with many points
+fn test_sid_cheaper_than_standard_for_wide_windows() {
+    let standard = estimated_cost(1 << 16, 298, 16, PippengerVariant::Standard);
+    let sid = estimated_cost(1 << 16, 298, 16, PippengerVariant::Sid);
+    assert!(sid < standard, "SID should be cheaper than unsigned buckets for wide windows");
+}
+
+#[test]
+// Straus windows should stay small, growing with the scalar width but within the table limit
+fn test_straus_window_size() {
+    let narrow = straus_window_size(32);
+    let wide = straus_window_size(298);
+
+    assert!((MIN_WINDOW_SIZE..=MAX_STRAUS_WINDOW_SIZE).contains(&narrow), "Straus window {} is out of range", narrow);
+    assert!(wide >= narrow, "Wider scalars should not use a narrower Straus window");
+    assert!(wide <= MAX_STRAUS_WINDOW_SIZE, "Straus window should not exceed the table limit");
+    assert_eq!(straus_window_size(1), 1, "One-bit scalars need a window of one");
+}
+
+#[test]
+// Signed digits halve the table, so wNAF can afford a window at least as wide as Straus
+fn test_wnaf_window_size() {
+    for scalar_bits in [32, 298, 753] {
+        let window_size = wnaf_window_size(scalar_bits);
+        assert!((MIN_SID_WINDOW_SIZE..=MAX_STRAUS_WINDOW_SIZE).contains(&window_size), "wNAF window {} is out of range", window_size);
+        assert!(window_size >= straus_window_size(scalar_bits), "wNAF window should not be narrower than the Straus window");
+    }
+    assert_eq!(wnaf_window_size(1), MIN_SID_WINDOW_SIZE, "wNAF windows should never drop below 2");
+}
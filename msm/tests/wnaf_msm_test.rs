@@ -0,0 +1,126 @@
+use msm::wnaf_msm::{wnaf_msm, wnaf_msm_field, try_wnaf_msm, try_wnaf_msm_field, wnaf_combine};
+use msm::wnaf::wnaf_digits;
+use msm::naive::{naive_msm, naive_msm_field};
+use msm::error::MsmError;
+use msm::window::MAX_STRAUS_WINDOW_SIZE;
+use ark_mnt4_298::{G1Projective, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+#[test]
+fn test_wnaf_msm_with_zero_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![0, 0];
+    let window_size = 3;
+    assert_eq!(wnaf_msm(&points, &scalars, window_size), G1Projective::zero(), "wNAF MSM with zero scalars should return the zero point");
+}
+
+#[test]
+fn test_wnaf_msm_with_max_scalars() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng), G1Projective::rand(&mut rng)];
+    let scalars = vec![u32::MAX, u32::MAX];
+    let window_size = 4;
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(wnaf_msm(&points, &scalars, window_size), expected_result, "wNAF MSM with maximum scalars failed");
+}
+
+#[test]
+fn test_wnaf_msm_with_empty_lists() {
+    let points: Vec<G1Projective> = Vec::new();
+    let scalars: Vec<u32> = Vec::new();
+    let window_size = 3;
+    assert_eq!(wnaf_msm(&points, &scalars, window_size), G1Projective::zero(), "wNAF MSM with empty lists should return the zero point");
+}
+
+#[test]
+#[should_panic(expected = "Points and scalars must have the same length")]
+fn test_wnaf_msm_with_different_lengths() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1, 2];
+    let window_size = 3;
+    let panic_result = wnaf_msm(&points, &scalars, window_size); // This should panic
+    assert_eq!(panic_result, G1Projective::zero())
+}
+
+#[test]
+fn test_try_wnaf_msm_with_window_size_one() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let scalars = vec![1];
+    assert_eq!(try_wnaf_msm(&points, &scalars, 1), Err(MsmError::InvalidWindowSize { window_size: 1, min: 2, max: MAX_STRAUS_WINDOW_SIZE }), "Window size 1 should be rejected");
+}
+
+#[test]
+// Every point would store 2^18 odd multiples, so the window is rejected instead of exhausting memory
+fn test_try_wnaf_msm_with_oversized_window() {
+    let mut rng = test_rng();
+    let points = vec![G1Projective::rand(&mut rng)];
+    let expected = Err(MsmError::InvalidWindowSize { window_size: 20, min: 2, max: MAX_STRAUS_WINDOW_SIZE });
+    assert_eq!(try_wnaf_msm(&points, &[1], 20), expected, "Oversized window should be rejected");
+    assert_eq!(try_wnaf_msm_field(&points, &[Fr::from(1u64)], 20), expected, "Oversized window should be rejected");
+    assert!(try_wnaf_msm(&points, &[1], MAX_STRAUS_WINDOW_SIZE).is_ok(), "The largest wNAF window should be accepted");
+}
+
+// Helper function to generate n points
+fn generate_points(num_points: usize) -> Vec<G1Projective> {
+    let mut rng = test_rng();
+    (0..num_points).map(|_| G1Projective::rand(&mut rng)).collect()
+}
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Helper function to generate n random full-width field scalars
+fn generate_field_scalars(num_scalars: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..num_scalars).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+#[test]
+// The shared doubling chain should combine the signed digits of every point
+fn test_wnaf_combine() {
+    let points = generate_points(10);
+    let scalars = generate_scalars(10);
+    let window_size = 4;
+
+    let digits: Vec<Vec<i64>> = scalars.iter().map(|&scalar| wnaf_digits(scalar, window_size)).collect();
+    let combined_result = wnaf_combine(&points, &digits, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm(&points, &scalars);
+    assert_eq!(combined_result, expected_result, "Combined MSM result is incorrect");
+}
+
+#[test]
+// "Comprehensive test with 200 points"
+fn test_wnaf_msm_algorithm() {
+    let points = generate_points(200);
+    let scalars = generate_scalars(200);
+    let expected_result = naive_msm(&points, &scalars);
+
+    for window_size in 2..=7 {
+        let msm_result = wnaf_msm(&points, &scalars, window_size);
+        // Compare against result from naive msm
+        assert_eq!(msm_result, expected_result, "wNAF MSM with window size {} did not match expected result", window_size);
+    }
+}
+
+#[test]
+// "Comprehensive test with 50 points and full-width field scalars"
+fn test_wnaf_msm_field_algorithm() {
+    let points = generate_points(50);
+    let scalars = generate_field_scalars(50);
+    let window_size = 5;
+
+    let msm_result = wnaf_msm_field(&points, &scalars, window_size);
+    // Compare against result from naive msm
+    let expected_result = naive_msm_field(&points, &scalars);
+    assert_eq!(msm_result, expected_result, "wNAF MSM with field scalars did not match expected result");
+}
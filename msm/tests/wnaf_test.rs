@@ -0,0 +1,106 @@
+use msm::wnaf::{wnaf_recode, wnaf_digits, wnaf_digits_field};
+use ark_mnt4_298::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{test_rng, UniformRand};
+use rand::{Rng, thread_rng};
+
+// Helper function to generate n random scalars of type u32
+fn generate_scalars(num_scalars: usize) -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..num_scalars).map(|_| rng.gen()).collect()
+}
+
+// Checks the digits are a valid wNAF: odd digits below 2^(window_size - 1) in absolute value, at most one non-zero digit in any window_size consecutive digits
+fn assert_valid_wnaf(digits: &[i64], window_size: usize) {
+    let mut last_non_zero: Option<usize> = None;
+    for (index, &digit) in digits.iter().enumerate() {
+        if digit == 0 {
+            continue;
+        }
+        assert!(digit % 2 != 0, "Digit {} at {} is not odd", digit, index);
+        assert!(digit.unsigned_abs() < 1 << (window_size - 1), "Digit {} at {} is too large for window size {}", digit, index, window_size);
+        if let Some(previous) = last_non_zero {
+            assert!(index - previous >= window_size, "Non-zero digits at {} and {} are closer than window size {}", previous, index, window_size);
+        }
+        last_non_zero = Some(index);
+    }
+}
+
+#[test]
+// 7 = 8 - 1 gives the digits -1 at bit 0 and 1 at bit 3 for window size 2
+fn test_wnaf_digits() {
+    let digits = wnaf_digits(7, 2);
+
+    assert_eq!(digits.len(), 33, "There should be one digit per bit plus one for the final carry");
+    assert_eq!(digits[0], -1, "Incorrect digit at bit 0");
+    assert_eq!(digits[3], 1, "Incorrect digit at bit 3");
+    assert_eq!(digits.iter().filter(|&&digit| digit != 0).count(), 2, "There should be two non-zero digits");
+}
+
+#[test]
+// The largest u32 should carry into the extra top digit
+fn test_wnaf_digits_with_max_scalar() {
+    let digits = wnaf_digits(u32::MAX, 4);
+    assert_eq!(digits[32], 1, "The final carry should land in the extra top digit");
+    assert_valid_wnaf(&digits, 4);
+}
+
+#[test]
+fn test_wnaf_digits_with_zero_scalar() {
+    assert!(wnaf_digits(0, 3).iter().all(|&digit| digit == 0), "Zero should have no non-zero digits");
+}
+
+#[test]
+// Every recoding should be a valid wNAF that reconstructs its scalar
+fn test_wnaf_digits_reconstruct_scalar() {
+    for scalar in generate_scalars(200).into_iter().chain([1, 2, u32::MAX, u32::MAX - 1, 1 << 31]) {
+        for window_size in 2..=8 {
+            let digits = wnaf_digits(scalar, window_size);
+            assert_valid_wnaf(&digits, window_size);
+
+            let reconstructed: i128 = digits.iter().enumerate().map(|(index, &digit)| (digit as i128) << index).sum();
+            assert_eq!(reconstructed, scalar as i128, "Digits do not reconstruct {} with window size {}", scalar, window_size);
+        }
+    }
+}
+
+#[test]
+// Full-width field scalars should also recode into a valid wNAF that reconstructs the scalar bit by bit
+fn test_wnaf_digits_field_reconstruct_scalar() {
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        let scalar = Fr::rand(&mut rng);
+        let window_size = 5;
+        let digits = wnaf_digits_field(&scalar, window_size);
+        assert_eq!(digits.len(), Fr::MODULUS_BIT_SIZE as usize + 1, "There should be one digit per bit plus one for the final carry");
+        assert_valid_wnaf(&digits, window_size);
+
+        // Reconstruct in the field with Horner's rule from the top digit down
+        let two = Fr::from(2u64);
+        let reconstructed = digits.iter().rev().fold(Fr::from(0u64), |acc, &digit| {
+            let digit = if digit < 0 { -Fr::from(digit.unsigned_abs()) } else { Fr::from(digit as u64) };
+            acc * two + digit
+        });
+        assert_eq!(reconstructed, scalar, "Digits do not reconstruct the field scalar");
+        assert!(scalar.into_bigint().num_bits() as usize <= digits.len(), "Scalar is wider than its digits");
+    }
+}
+
+#[test]
+// wNAF should have no more non-zero digits than the fixed windows of signed integer decomposition, about 1 in window_size + 1
+fn test_wnaf_density() {
+    let scalars = generate_scalars(1000);
+    let window_size = 4;
+    let non_zero: usize = scalars.iter()
+        .map(|&scalar| wnaf_digits(scalar, window_size).iter().filter(|&&digit| digit != 0).count())
+        .sum();
+
+    let average = non_zero as f64 / scalars.len() as f64;
+    assert!(average <= 32.0 / window_size as f64, "Average of {} non-zero digits is denser than fixed windows", average);
+}
+
+#[test]
+#[should_panic(expected = "wNAF needs a window size of at least 2")]
+fn test_wnaf_recode_with_window_size_one() {
+    wnaf_recode(|_| true, 8, 1);
+}